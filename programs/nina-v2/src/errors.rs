@@ -12,4 +12,162 @@ pub enum NinaError {
     ArithmeticError,
     #[msg("Delegated Payer Mismatch")]
     DelegatedPayerMismatch,
+    #[msg("Voucher expired")]
+    VoucherExpired,
+    #[msg("Voucher signature missing")]
+    VoucherSignatureMissing,
+    #[msg("Voucher signature invalid")]
+    VoucherSignatureInvalid,
+    #[msg("Voucher signer not authorized")]
+    VoucherUnauthorizedSigner,
+    #[msg("Release Airdrop exceeds supply")]
+    ReleaseAirdropExceedsSupply,
+    #[msg("Release Airdrop invalid remaining accounts")]
+    ReleaseAirdropInvalidRemainingAccounts,
+    #[msg("Release Airdrop wrong token account")]
+    ReleaseAirdropWrongTokenAccount,
+    #[msg("Release Gift message too long")]
+    ReleaseGiftMessageTooLong,
+    #[msg("Bundle invalid number of releases")]
+    BundleInvalidReleaseCount,
+    #[msg("Bundle shares must add up to 100%")]
+    BundleInvalidShares,
+    #[msg("Bundle release mismatch")]
+    BundleReleaseMismatch,
+    #[msg("Bundle invalid remaining accounts")]
+    BundleInvalidRemainingAccounts,
+    #[msg("Bundle Purchase wrong amount")]
+    BundlePurchaseWrongAmount,
+    #[msg("Album release was not created as a group member")]
+    AlbumReleaseNotGroupable,
+    #[msg("Album mint is not a group")]
+    AlbumNotGroup,
+    #[msg("Album release is not a member of this album")]
+    AlbumReleaseNotMember,
+    #[msg("Album invalid remaining accounts")]
+    AlbumInvalidRemainingAccounts,
+    #[msg("Release resale royalty too high")]
+    ReleaseInvalidResaleRoyalty,
+    #[msg("Release transfer hook not enabled")]
+    ReleaseTransferHookNotEnabled,
+    #[msg("Release transfer hook called outside of a transfer")]
+    ReleaseTransferHookNotTransferring,
+    #[msg("Release transfer not approved")]
+    ReleaseTransferNotApproved,
+    #[msg("Listing not supported for transfer hook releases")]
+    ListingTransferHookRelease,
+    #[msg("Listing Buy wrong amount")]
+    ListingBuyWrongAmount,
+    #[msg("Release can't be non-transferable and use a transfer hook")]
+    ReleaseNonTransferableTransferHook,
+    #[msg("Release mint supply must be zero to close")]
+    ReleaseMintSupplyNotZero,
+    #[msg("Release mint was not created with a close authority")]
+    ReleaseMintNotClosable,
+    #[msg("Release mint was not created with a permanent delegate")]
+    ReleaseRevokeNotEnabled,
+    #[msg("Release metadata key is empty, too long or reserved")]
+    ReleaseMetadataInvalidKey,
+    #[msg("Release metadata value too long")]
+    ReleaseMetadataValueTooLong,
+    #[msg("Release metadata has too many fields")]
+    ReleaseMetadataTooManyFields,
+    #[msg("Release name too long")]
+    ReleaseNameTooLong,
+    #[msg("Release symbol too long")]
+    ReleaseSymbolTooLong,
+    #[msg("Release uri too long")]
+    ReleaseUriTooLong,
+    #[msg("Release uri must use https, ar or ipfs")]
+    ReleaseInvalidUriScheme,
+    #[msg("Release total supply must be greater than zero")]
+    ReleaseTotalSupplyZero,
+    #[msg("Release total supply below amount already minted")]
+    ReleaseTotalSupplyBelowMinted,
+    #[msg("Release sale end must be in the future")]
+    ReleaseInvalidSaleEnd,
+    #[msg("Release sale has ended")]
+    ReleaseSaleEnded,
+    #[msg("Release reveal uri does not match commitment")]
+    ReleaseRevealUriMismatch,
+    #[msg("Release is crowdfunding, use crowdfund_purchase")]
+    ReleaseCrowdfunding,
+    #[msg("Crowdfund needs an amount or copies goal")]
+    CrowdfundInvalidGoal,
+    #[msg("Crowdfund deadline must be in the future")]
+    CrowdfundInvalidDeadline,
+    #[msg("Crowdfund release already has sales")]
+    CrowdfundReleaseHasSales,
+    #[msg("Crowdfund has ended")]
+    CrowdfundEnded,
+    #[msg("Crowdfund deadline has not passed")]
+    CrowdfundNotEnded,
+    #[msg("Crowdfund goal was met")]
+    CrowdfundGoalMet,
+    #[msg("Crowdfund goal not met")]
+    CrowdfundGoalNotMet,
+    #[msg("Release date must be in the future")]
+    ReleaseInvalidReleaseDate,
+    #[msg("Release already has a release date")]
+    ReleasePreorderExists,
+    #[msg("Release is not out yet, use release_preorder")]
+    ReleaseNotYetReleased,
+    #[msg("Release pre-order has closed")]
+    ReleasePreorderClosed,
+    #[msg("Release pre-order was cancelled")]
+    ReleasePreorderCancelled,
+    #[msg("Release pre-order was not cancelled")]
+    ReleasePreorderNotCancelled,
+    #[msg("Hub fee must not exceed basis points")]
+    HubInvalidFee,
+    #[msg("Hub and release payment mints do not match")]
+    HubPaymentMintMismatch,
+    #[msg("Release referral fee must not exceed basis points")]
+    ReleaseInvalidReferralFee,
+    #[msg("Receiver cannot refer their own purchase")]
+    ReleaseSelfReferral,
+    #[msg("Profile handle must be between 1 and 32 characters")]
+    ProfileInvalidHandle,
+    #[msg("Release profile and release profile link must be provided together")]
+    ReleaseProfileMismatch,
+    #[msg("Only the identity service can verify profiles")]
+    ProfileUnauthorizedVerifier,
+    #[msg("Signer is not the release authority or a label manager with the required roles")]
+    ReleaseUnauthorized,
+    #[msg("Label has too many managers")]
+    LabelTooManyManagers,
+    #[msg("Multisig threshold must be between 1 and the number of members")]
+    MultisigInvalidThreshold,
+    #[msg("Multisig members must be unique and at most 10")]
+    MultisigInvalidMembers,
+    #[msg("Signer is not a multisig member")]
+    MultisigNotMember,
+    #[msg("Multisig proposal has not reached its threshold")]
+    MultisigThresholdNotMet,
+    #[msg("Multisig proposal already executed")]
+    MultisigProposalExecuted,
+    #[msg("Multisig payout token account does not match proposal")]
+    MultisigPayoutMismatch,
+    #[msg("Release tip must be greater than zero")]
+    ReleaseTipZero,
+    #[msg("Release tips require holding the release")]
+    ReleaseTipRequiresHolder,
+    #[msg("Release price must be zero to claim")]
+    ReleaseNotFree,
+    #[msg("Release claim limit reached for this wallet")]
+    ReleaseClaimLimitReached,
+    #[msg("Release claim requires a voucher")]
+    ReleaseClaimVoucherRequired,
+    #[msg("Release is numbered, copies are bought with release_purchase_numbered")]
+    ReleaseNumbered,
+    #[msg("Release is not numbered")]
+    ReleaseNotNumbered,
+    #[msg("Release uri is committed until it is revealed")]
+    ReleaseRevealPending,
+    #[msg("Release mint extensions can't be carried over to tiers")]
+    ReleaseTierUnsupportedExtensions,
+    #[msg("Numbered release total supply can't change")]
+    ReleaseNumberedSupplyFixed,
+    #[msg("Release has pre-orders that are not fulfilled or reclaimed")]
+    ReleasePreordersOutstanding,
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ReleaseAirdropped {
+    pub release: Pubkey,
+    pub authority: Pubkey,
+    pub recipients: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ReleaseGifted {
+    pub release: Pubkey,
+    pub buyer: Pubkey,
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub message: Option<String>,
+}
+
+#[event]
+pub struct ReleasePurchased {
+    pub release: Pubkey,
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub referrer: Option<Pubkey>,
+    pub referral_amount: u64,
+}
+
+#[event]
+pub struct HubReleasePurchased {
+    pub release: Pubkey,
+    pub hub: Pubkey,
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub hub_fee: u64,
+}
+
+#[event]
+pub struct ReleaseTipped {
+    pub release: Pubkey,
+    pub tipper: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReleaseRevealed {
+    pub release: Pubkey,
+    pub uri: String,
+}
+
+#[event]
+pub struct ReleaseEditionPurchased {
+    pub release: Pubkey,
+    pub receiver: Pubkey,
+    pub mint: Pubkey,
+    pub edition: u64,
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::extension::group_member_pointer::GroupMemberPointer,
+    token_interface::{
+        token_member_initialize,
+        Mint,
+        Token2022,
+        TokenMemberInitialize,
+    },
+};
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_UPDATE_METADATA};
+use crate::instructions::release_init_v2::update_mint_balance;
+use crate::utils::{file_service_account_key, get_mint_extension_data, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct AlbumAddRelease<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", album_mint.key().as_ref()],
+        bump,
+    )]
+    pub album_release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is checked against album_release
+    #[account(
+        constraint = album_release_signer.key() == album_release.release_signer,
+    )]
+    pub album_release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = album_mint.key() == album_release.mint,
+    )]
+    pub album_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is checked against release
+    #[account(
+        constraint = release_signer.key() == release.release_signer,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+pub fn handler(
+    ctx: Context<AlbumAddRelease>,
+    album_release_signer_bump: u8,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.album_release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_UPDATE_METADATA,
+    )?;
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_UPDATE_METADATA,
+    )?;
+
+    // only releases whose mint was created with a member pointer to itself can join a group
+    let member_pointer = get_mint_extension_data::<GroupMemberPointer>(&mut ctx.accounts.mint.to_account_info())
+        .map_err(|_| NinaError::AlbumReleaseNotGroupable)?;
+    if Option::<Pubkey>::from(member_pointer.member_address) != Some(ctx.accounts.mint.key()) {
+        return Err(error!(NinaError::AlbumReleaseNotGroupable));
+    }
+
+    let cpi_accounts = TokenMemberInitialize {
+        program_id: ctx.accounts.token_2022_program.to_account_info(),
+        member: ctx.accounts.mint.to_account_info(),
+        member_mint: ctx.accounts.mint.to_account_info(),
+        member_mint_authority: ctx.accounts.release_signer.to_account_info(),
+        group: ctx.accounts.album_mint.to_account_info(),
+        group_update_authority: ctx.accounts.album_release_signer.to_account_info(),
+    };
+
+    let album_release_key = ctx.accounts.album_release.key();
+    let release_key = ctx.accounts.release.key();
+    let album_seeds = &[
+        album_release_key.as_ref(),
+        &[album_release_signer_bump],
+    ];
+    let release_seeds = &[
+        release_key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&album_seeds[..], &release_seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token_member_initialize(cpi_ctx)?;
+
+    ctx.accounts.mint.reload()?;
+
+    update_mint_balance(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        token_group_initialize,
+        Mint,
+        Token2022,
+        TokenAccount,
+        TokenGroupInitialize,
+    },
+};
+
+use crate::state::ReleaseV2;
+use crate::instructions::release_init_v2::{
+    set_release_data,
+    initialize_token_metadata,
+    update_mint_balance,
+    validate_release_metadata,
+    validate_total_supply,
+};
+use crate::utils::file_service_account_key;
+use crate::errors::NinaError;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct AlbumInitArgs {
+    pub uri: String,
+    pub name: String,
+    pub symbol: String,
+    pub total_supply: u64,
+    pub price: u64,
+    pub release_signer_bump: u8,
+    pub max_size: u64,
+}
+
+#[derive(Accounts)]
+pub struct AlbumInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: can be any account
+    pub authority: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [b"nina-release", mint.key.as_ref()],
+        bump,
+        payer = payer,
+        space = 232,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    #[account(
+        init,
+        payer = payer,
+        mint::token_program = token_2022_program,
+        mint::decimals = 0,
+        mint::authority = release_signer,
+        extensions::metadata_pointer::authority = release_signer,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::group_pointer::authority = release_signer,
+        extensions::group_pointer::group_address = mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = authority,
+    )]
+    pub royalty_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn initialize_token_group<'info>(
+    token_2022_program: &Program<'info, Token2022>,
+    mint: &InterfaceAccount<'info, Mint>,
+    release: &Account<'info, ReleaseV2>,
+    release_signer: &UncheckedAccount<'info>,
+    max_size: u64,
+    release_signer_bump: u8,
+) -> Result<()> {
+    let cpi_accounts = TokenGroupInitialize {
+        program_id: token_2022_program.to_account_info(),
+        group: mint.to_account_info(),
+        mint: mint.to_account_info(),
+        mint_authority: release_signer.to_account_info(),
+    };
+
+    let seeds = &[
+        release.to_account_info().key.as_ref(),
+        &[release_signer_bump],
+    ];
+
+    let signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer
+    );
+    token_group_initialize(cpi_ctx, Some(release_signer.key()), max_size)
+}
+
+pub fn handler(
+    ctx: Context<AlbumInit>,
+    args: AlbumInitArgs,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_metadata(&args.name, &args.symbol, &args.uri)?;
+    validate_total_supply(args.total_supply, 0, false)?;
+
+    initialize_token_metadata(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        args.name,
+        args.symbol,
+        args.uri,
+        args.release_signer_bump,
+    )?;
+
+    initialize_token_group(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        args.max_size,
+        args.release_signer_bump,
+    )?;
+
+    ctx.accounts.mint.reload()?;
+
+    update_mint_balance(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    set_release_data(
+        &mut ctx.accounts.release,
+        &ctx.accounts.authority,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.payment_mint,
+        args.total_supply,
+        args.price,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{AssociatedToken, get_associated_token_address_with_program_id},
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use spl_token_group_interface::state::{TokenGroup, TokenGroupMember};
+
+use crate::state::ReleaseV2;
+use crate::instructions::release_purchase::{
+    validate_purchase,
+    validate_supply,
+    transfer_payment,
+    mint_release_token,
+    create_release_token_account,
+    mint_release_token_to,
+};
+use crate::utils::{get_mint_extension_data, id_account_key};
+use crate::errors::NinaError;
+
+pub const ALBUM_PURCHASE_ACCOUNTS_PER_MEMBER: usize = 4;
+
+#[derive(Accounts)]
+#[instruction(
+  amount: u64,
+  release_signer_bump: u8,
+)]
+pub struct AlbumPurchase<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+      mut,
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = payment_token_account.mint == release.payment_mint,
+      constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = royalty_token_account.key() == release.royalty_token_account,
+      constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// remaining accounts, for every member of the album group:
+// [release, release_signer, mint, receiver_release_token_account]
+pub fn handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, AlbumPurchase<'info>>,
+    amount: u64,
+    release_signer_bump: u8,
+    member_release_signer_bumps: Vec<u8>,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    let album_mint = ctx.accounts.mint.key();
+    let group = get_mint_extension_data::<TokenGroup>(&mut ctx.accounts.mint.to_account_info())
+        .map_err(|_| NinaError::AlbumNotGroup)?;
+
+    let member_accounts = ctx.remaining_accounts.chunks_exact(ALBUM_PURCHASE_ACCOUNTS_PER_MEMBER);
+    if !member_accounts.remainder().is_empty()
+        || member_accounts.len() as u64 != u64::from(group.size)
+        || member_release_signer_bumps.len() != member_accounts.len()
+    {
+        return Err(error!(NinaError::AlbumInvalidRemainingAccounts));
+    }
+
+    validate_purchase(&ctx.accounts.release, &ctx.accounts.mint, amount)?;
+
+    transfer_payment(
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.receiver,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    mint_release_token(
+        &ctx.accounts.mint,
+        &ctx.accounts.receiver_release_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )?;
+
+    let mut member_mints: Vec<Pubkey> = Vec::with_capacity(member_accounts.len());
+    for (accounts, member_release_signer_bump) in member_accounts.zip(member_release_signer_bumps) {
+        let release = Account::<ReleaseV2>::try_from(&accounts[0])?;
+        let release_signer = UncheckedAccount::try_from(&accounts[1]);
+        let mint = InterfaceAccount::<Mint>::try_from(&accounts[2])?;
+        let receiver_release_token_account = &accounts[3];
+
+        if release_signer.key() != release.release_signer
+            || mint.key() != release.mint
+            || member_mints.contains(&mint.key())
+        {
+            return Err(error!(NinaError::AlbumInvalidRemainingAccounts));
+        }
+
+        let member = get_mint_extension_data::<TokenGroupMember>(&mut mint.to_account_info())
+            .map_err(|_| NinaError::AlbumReleaseNotMember)?;
+        if member.group != album_mint {
+            return Err(error!(NinaError::AlbumReleaseNotMember));
+        }
+
+        let expected_token_account = get_associated_token_address_with_program_id(
+            &ctx.accounts.receiver.key(),
+            &mint.key(),
+            &ctx.accounts.token_2022_program.key(),
+        );
+        if receiver_release_token_account.key() != expected_token_account {
+            return Err(error!(NinaError::AlbumInvalidRemainingAccounts));
+        }
+
+        validate_supply(&release, &mint)?;
+
+        create_release_token_account(
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.receiver.to_account_info(),
+            receiver_release_token_account.to_account_info(),
+            mint.to_account_info(),
+            &ctx.accounts.system_program,
+            &ctx.accounts.associated_token_program,
+            &ctx.accounts.token_2022_program,
+        )?;
+
+        mint_release_token_to(
+            mint.to_account_info(),
+            receiver_release_token_account.to_account_info(),
+            &release_signer,
+            &release,
+            &ctx.accounts.token_2022_program,
+            member_release_signer_bump,
+        )?;
+
+        member_mints.push(mint.key());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{Bundle, BundleRelease, ReleaseV2, BUNDLE_MAX_RELEASES};
+use crate::instructions::release_purchase::BASIS_POINTS;
+use crate::utils::file_service_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+#[instruction(bundle_id: u64)]
+pub struct BundleInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"nina-bundle", authority.key().as_ref(), bundle_id.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 492,
+    )]
+    pub bundle: Account<'info, Bundle>,
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+}
+
+// remaining accounts: the releases in the bundle, in the same order as shares
+pub fn handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, BundleInit<'info>>,
+    bundle_id: u64,
+    price: u64,
+    shares: Vec<u64>,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    let release_accounts = ctx.remaining_accounts;
+    if release_accounts.len() < 2 || release_accounts.len() > BUNDLE_MAX_RELEASES {
+        return Err(error!(NinaError::BundleInvalidReleaseCount));
+    }
+    if shares.len() != release_accounts.len() {
+        return Err(error!(NinaError::BundleInvalidShares));
+    }
+
+    let total_shares = shares
+        .iter()
+        .try_fold(0u64, |total, share| total.checked_add(*share))
+        .ok_or(NinaError::ArithmeticError)?;
+    if total_shares != BASIS_POINTS {
+        return Err(error!(NinaError::BundleInvalidShares));
+    }
+
+    let mut releases = Vec::with_capacity(release_accounts.len());
+    for (release_info, share) in release_accounts.iter().zip(shares) {
+        let release = Account::<ReleaseV2>::try_from(release_info)?;
+        if release.authority != ctx.accounts.authority.key()
+            || release.payment_mint != ctx.accounts.payment_mint.key()
+            || releases.iter().any(|bundle_release: &BundleRelease| bundle_release.release == release.key())
+        {
+            return Err(error!(NinaError::BundleReleaseMismatch));
+        }
+
+        releases.push(BundleRelease {
+            release: release.key(),
+            share,
+        });
+    }
+
+    let bundle = &mut ctx.accounts.bundle;
+    bundle.authority = ctx.accounts.authority.key();
+    bundle.payment_mint = ctx.accounts.payment_mint.key();
+    bundle.bundle_id = bundle_id;
+    bundle.price = price;
+    bundle.releases = releases;
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{AssociatedToken, get_associated_token_address_with_program_id},
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Bundle, ReleaseV2};
+use crate::instructions::release_purchase::{
+    validate_supply,
+    transfer_payment,
+    create_release_token_account,
+    mint_release_token_to,
+    BASIS_POINTS,
+};
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+pub const BUNDLE_PURCHASE_ACCOUNTS_PER_RELEASE: usize = 5;
+
+#[derive(Accounts)]
+pub struct BundlePurchase<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+    pub bundle: Box<Account<'info, Bundle>>,
+    #[account(
+      constraint = payment_mint.key() == bundle.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = payment_token_account.mint == bundle.payment_mint,
+      constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// remaining accounts, for each release in bundle order:
+// [release, release_signer, mint, royalty_token_account, receiver_release_token_account]
+pub fn handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, BundlePurchase<'info>>,
+    amount: u64,
+    release_signer_bumps: Vec<u8>,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    let bundle = &ctx.accounts.bundle;
+    if amount != bundle.price {
+        return Err(error!(NinaError::BundlePurchaseWrongAmount));
+    }
+
+    let release_accounts = ctx.remaining_accounts.chunks_exact(BUNDLE_PURCHASE_ACCOUNTS_PER_RELEASE);
+    if !release_accounts.remainder().is_empty()
+        || release_accounts.len() != bundle.releases.len()
+        || release_signer_bumps.len() != bundle.releases.len()
+    {
+        return Err(error!(NinaError::BundleInvalidRemainingAccounts));
+    }
+
+    let mut amount_remaining = amount;
+    for (index, accounts) in release_accounts.enumerate() {
+        let bundle_release = &bundle.releases[index];
+        let release = Account::<ReleaseV2>::try_from(&accounts[0])?;
+        let release_signer = UncheckedAccount::try_from(&accounts[1]);
+        let mint = InterfaceAccount::<Mint>::try_from(&accounts[2])?;
+        let royalty_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[3])?;
+        let receiver_release_token_account = &accounts[4];
+
+        if release.key() != bundle_release.release
+            || release_signer.key() != release.release_signer
+            || mint.key() != release.mint
+            || royalty_token_account.key() != release.royalty_token_account
+        {
+            return Err(error!(NinaError::BundleReleaseMismatch));
+        }
+
+        let expected_token_account = get_associated_token_address_with_program_id(
+            &ctx.accounts.receiver.key(),
+            &mint.key(),
+            &ctx.accounts.token_2022_program.key(),
+        );
+        if receiver_release_token_account.key() != expected_token_account {
+            return Err(error!(NinaError::BundleInvalidRemainingAccounts));
+        }
+
+        validate_supply(&release, &mint)?;
+
+        // the last release takes whatever rounding leaves over
+        let release_amount = if index == bundle.releases.len() - 1 {
+            amount_remaining
+        } else {
+            amount
+                .checked_mul(bundle_release.share)
+                .ok_or(NinaError::ArithmeticError)?
+                .checked_div(BASIS_POINTS)
+                .ok_or(NinaError::ArithmeticError)?
+        };
+        amount_remaining = amount_remaining
+            .checked_sub(release_amount)
+            .ok_or(NinaError::ArithmeticError)?;
+
+        if release_amount > 0 {
+            transfer_payment(
+                &ctx.accounts.payment_token_account,
+                &royalty_token_account,
+                &ctx.accounts.receiver,
+                &ctx.accounts.token_program,
+                release_amount,
+            )?;
+        }
+
+        create_release_token_account(
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.receiver.to_account_info(),
+            receiver_release_token_account.to_account_info(),
+            mint.to_account_info(),
+            &ctx.accounts.system_program,
+            &ctx.accounts.associated_token_program,
+            &ctx.accounts.token_2022_program,
+        )?;
+
+        mint_release_token_to(
+            mint.to_account_info(),
+            receiver_release_token_account.to_account_info(),
+            &release_signer,
+            &release,
+            &ctx.accounts.token_2022_program,
+            release_signer_bumps[index],
+        )?;
+    }
+
+    Ok(())
+}
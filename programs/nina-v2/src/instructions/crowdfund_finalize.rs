@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{
+        close_account,
+        transfer,
+        CloseAccount,
+        Token,
+        Transfer,
+    },
+    token_interface::{
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Crowdfund, ReleaseV2};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct CrowdfundFinalize<'info> {
+    /// CHECK: This is safe because it is checked against release, and only receives the escrow rent
+    #[account(
+        mut,
+        constraint = authority.key() == release.authority,
+    )]
+    pub authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+        mut,
+        seeds = [b"nina-crowdfund", release.key().as_ref()],
+        bump = crowdfund.bump,
+    )]
+    pub crowdfund: Box<Account<'info, Crowdfund>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = crowdfund,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = royalty_token_account.key() == release.royalty_token_account,
+        constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Anyone can finalize once the goal is met, moving the escrow to the royalty account
+// and opening the release up to regular purchases
+pub fn handler(
+    ctx: Context<CrowdfundFinalize>,
+) -> Result<()> {
+    if ctx.accounts.crowdfund.finalized {
+        return Err(error!(NinaError::CrowdfundEnded));
+    }
+    if !ctx.accounts.crowdfund.goal_met() {
+        return Err(error!(NinaError::CrowdfundGoalNotMet));
+    }
+
+    transfer_from_escrow(
+        &ctx.accounts.crowdfund,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.token_program,
+        ctx.accounts.escrow_token_account.amount,
+    )?;
+
+    let release_key = ctx.accounts.release.key();
+    let seeds = &[
+        b"nina-crowdfund".as_ref(),
+        release_key.as_ref(),
+        &[ctx.accounts.crowdfund.bump],
+    ];
+    let signer = &[&seeds[..]];
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.crowdfund.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    ctx.accounts.crowdfund.finalized = true;
+    ctx.accounts.release.crowdfunding = false;
+
+    Ok(())
+}
+
+pub fn transfer_from_escrow<'info>(
+    crowdfund: &Account<'info, Crowdfund>,
+    escrow_token_account: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"nina-crowdfund".as_ref(),
+        crowdfund.release.as_ref(),
+        &[crowdfund.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+            from: escrow_token_account.to_account_info(),
+            to: to.to_account_info(),
+            authority: crowdfund.to_account_info(),
+        },
+        signer,
+    );
+    transfer(cpi_ctx, amount)
+}
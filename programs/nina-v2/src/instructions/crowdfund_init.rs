@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Crowdfund, Label, ReleaseV2, LABEL_ROLE_PRICE};
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct CrowdfundInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [b"nina-crowdfund", release.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 82,
+    )]
+    pub crowdfund: Box<Account<'info, Crowdfund>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = crowdfund,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+pub fn handler(
+    ctx: Context<CrowdfundInit>,
+    goal_amount: u64,
+    goal_copies: u64,
+    deadline: i64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_PRICE,
+    )?;
+
+    if goal_amount == 0 && goal_copies == 0 {
+        return Err(error!(NinaError::CrowdfundInvalidGoal));
+    }
+    if deadline <= Clock::get()?.unix_timestamp {
+        return Err(error!(NinaError::CrowdfundInvalidDeadline));
+    }
+    // a pre-order already holds buyers' payments in its own escrow
+    if ctx.accounts.release.release_date != 0 || ctx.accounts.release.preorder_cancelled {
+        return Err(error!(NinaError::ReleasePreorderExists));
+    }
+    // every sale has to go through the escrow for refunds to add up
+    if ctx.accounts.mint.supply != 0 {
+        return Err(error!(NinaError::CrowdfundReleaseHasSales));
+    }
+
+    let crowdfund = &mut ctx.accounts.crowdfund;
+    crowdfund.release = ctx.accounts.release.key();
+    crowdfund.goal_amount = goal_amount;
+    crowdfund.goal_copies = goal_copies;
+    crowdfund.deadline = deadline;
+    crowdfund.bump = ctx.bumps.crowdfund;
+
+    ctx.accounts.release.crowdfunding = true;
+
+    Ok(())
+}
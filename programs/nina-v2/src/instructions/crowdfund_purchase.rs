@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Crowdfund, CrowdfundContribution, ReleaseV2};
+use crate::instructions::release_purchase::{
+    validate_edition_available,
+    transfer_payment,
+    mint_release_token,
+};
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct CrowdfundPurchase<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"nina-crowdfund", release.key().as_ref()],
+        bump = crowdfund.bump,
+    )]
+    pub crowdfund: Box<Account<'info, Crowdfund>>,
+    #[account(
+        init_if_needed,
+        seeds = [b"nina-contribution", crowdfund.key().as_ref(), receiver.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 88,
+    )]
+    pub contribution: Box<Account<'info, CrowdfundContribution>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = payment_token_account.mint == release.payment_mint,
+        constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = crowdfund,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<CrowdfundPurchase>,
+    amount: u64,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if ctx.accounts.crowdfund.finalized
+        || Clock::get()?.unix_timestamp >= ctx.accounts.crowdfund.deadline
+    {
+        return Err(error!(NinaError::CrowdfundEnded));
+    }
+    if amount != ctx.accounts.release.price {
+        return Err(error!(NinaError::ReleasePurchaseWrongAmount));
+    }
+    validate_edition_available(&ctx.accounts.release, &ctx.accounts.mint)?;
+
+    transfer_payment(
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.receiver,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    mint_release_token(
+        &ctx.accounts.mint,
+        &ctx.accounts.receiver_release_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )?;
+
+    let crowdfund = &mut ctx.accounts.crowdfund;
+    crowdfund.raised = crowdfund.raised.checked_add(amount).ok_or(NinaError::ArithmeticError)?;
+    crowdfund.copies = crowdfund.copies.checked_add(1).ok_or(NinaError::ArithmeticError)?;
+
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.crowdfund = crowdfund.key();
+    contribution.buyer = ctx.accounts.receiver.key();
+    contribution.amount = contribution.amount.checked_add(amount).ok_or(NinaError::ArithmeticError)?;
+    contribution.copies = contribution.copies.checked_add(1).ok_or(NinaError::ArithmeticError)?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Hub, HubRelease, Label, ReleaseV2, LABEL_ROLE_PRICE};
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct HubAddRelease<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == hub.authority,
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-hub", hub.authority.as_ref(), hub.hub_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    // the artist has to accept the hub and the fee it takes from each sale
+    pub release_authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"nina-hub-release", hub.key().as_ref(), release.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 72,
+    )]
+    pub hub_release: Account<'info, HubRelease>,
+    pub system_program: Program<'info, System>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+pub fn handler(
+    ctx: Context<HubAddRelease>,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.release_authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_PRICE,
+    )?;
+
+    let hub_release = &mut ctx.accounts.hub_release;
+    hub_release.hub = ctx.accounts.hub.key();
+    hub_release.release = ctx.accounts.release.key();
+
+    Ok(())
+}
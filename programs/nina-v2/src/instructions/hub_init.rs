@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Hub;
+use crate::instructions::release_init_v2::validate_release_uri;
+use crate::instructions::release_purchase::BASIS_POINTS;
+use crate::utils::file_service_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+#[instruction(hub_id: u64)]
+pub struct HubInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"nina-hub", authority.key().as_ref(), hub_id.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 260,
+    )]
+    pub hub: Account<'info, Hub>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<HubInit>,
+    hub_id: u64,
+    fee: u64,
+    uri: String,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if fee > BASIS_POINTS {
+        return Err(error!(NinaError::HubInvalidFee));
+    }
+    validate_release_uri(&uri)?;
+
+    let hub = &mut ctx.accounts.hub;
+    hub.authority = ctx.accounts.authority.key();
+    hub.hub_id = hub_id;
+    hub.fee = fee;
+    hub.uri = uri;
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Hub, HubRelease, ReleaseV2};
+use crate::instructions::release_purchase::{
+    calculate_fee,
+    validate_purchase,
+    transfer_payment,
+    mint_release_token,
+};
+use crate::events::HubReleasePurchased;
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct HubReleasePurchase<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+    #[account(
+        seeds = [b"nina-hub", hub.authority.as_ref(), hub.hub_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub hub: Box<Account<'info, Hub>>,
+    #[account(
+        seeds = [b"nina-hub-release", hub.key().as_ref(), release.key().as_ref()],
+        bump,
+    )]
+    pub hub_release: Box<Account<'info, HubRelease>>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = payment_token_account.mint == release.payment_mint,
+        constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = royalty_token_account.key() == release.royalty_token_account,
+        constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = hub_token_account.mint == release.payment_mint @ NinaError::HubPaymentMintMismatch,
+        constraint = hub_token_account.owner == hub.authority,
+    )]
+    pub hub_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Same as release_purchase, but the hub's fee comes out of the price
+// before the rest is paid to the release royalty account
+pub fn handler(
+    ctx: Context<HubReleasePurchase>,
+    amount: u64,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_purchase(&ctx.accounts.release, &ctx.accounts.mint, amount)?;
+
+    let hub_fee = calculate_fee(amount, ctx.accounts.hub.fee)?;
+    if hub_fee > 0 {
+        transfer_payment(
+            &ctx.accounts.payment_token_account,
+            &ctx.accounts.hub_token_account,
+            &ctx.accounts.receiver,
+            &ctx.accounts.token_program,
+            hub_fee,
+        )?;
+    }
+
+    if amount > hub_fee {
+        transfer_payment(
+            &ctx.accounts.payment_token_account,
+            &ctx.accounts.royalty_token_account,
+            &ctx.accounts.receiver,
+            &ctx.accounts.token_program,
+            amount - hub_fee,
+        )?;
+    }
+
+    mint_release_token(
+        &ctx.accounts.mint,
+        &ctx.accounts.receiver_release_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )?;
+
+    emit!(HubReleasePurchased {
+        release: ctx.accounts.release.key(),
+        hub: ctx.accounts.hub.key(),
+        receiver: ctx.accounts.receiver.key(),
+        amount,
+        hub_fee,
+    });
+
+    Ok(())
+}
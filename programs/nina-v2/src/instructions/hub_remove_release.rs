@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Hub, HubRelease};
+
+#[derive(Accounts)]
+pub struct HubRemoveRelease<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == hub.authority,
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-hub", hub.authority.as_ref(), hub.hub_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"nina-hub-release", hub.key().as_ref(), hub_release.release.as_ref()],
+        bump,
+    )]
+    pub hub_release: Account<'info, HubRelease>,
+}
+
+pub fn handler(
+    _ctx: Context<HubRemoveRelease>,
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Label;
+use crate::utils::file_service_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+#[instruction(label_id: u64)]
+pub struct LabelInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"nina-label", authority.key().as_ref(), label_id.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 383,
+    )]
+    pub label: Account<'info, Label>,
+    pub system_program: Program<'info, System>,
+}
+
+// The label PDA is used as release.authority for the releases it manages
+pub fn handler(
+    ctx: Context<LabelInit>,
+    label_id: u64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    let label = &mut ctx.accounts.label;
+    label.authority = ctx.accounts.authority.key();
+    label.label_id = label_id;
+    label.managers = vec![];
+    label.bump = ctx.bumps.label;
+
+    Ok(())
+}
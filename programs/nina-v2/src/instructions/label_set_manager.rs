@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Label, LabelManager, LABEL_MAX_MANAGERS};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct LabelSetManager<'info> {
+    #[account(
+        constraint = authority.key() == label.authority,
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-label", label.authority.as_ref(), label.label_id.to_le_bytes().as_ref()],
+        bump = label.bump,
+    )]
+    pub label: Account<'info, Label>,
+}
+
+// Sets the roles a manager holds, 0 removes the manager
+pub fn handler(
+    ctx: Context<LabelSetManager>,
+    manager: Pubkey,
+    roles: u8,
+) -> Result<()> {
+    let managers = &mut ctx.accounts.label.managers;
+    managers.retain(|label_manager| label_manager.manager != manager);
+
+    if roles != 0 {
+        if managers.len() >= LABEL_MAX_MANAGERS {
+            return Err(error!(NinaError::LabelTooManyManagers));
+        }
+        managers.push(LabelManager {
+            manager,
+            roles,
+        });
+    }
+
+    Ok(())
+}
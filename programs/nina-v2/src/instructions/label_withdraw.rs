@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Token, Transfer},
+    token_interface::{
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::Label;
+
+#[derive(Accounts)]
+pub struct LabelWithdraw<'info> {
+    #[account(
+        constraint = authority.key() == label.authority,
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-label", label.authority.as_ref(), label.label_id.to_le_bytes().as_ref()],
+        bump = label.bump,
+    )]
+    pub label: Account<'info, Label>,
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = label,
+    )]
+    pub label_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == payment_mint.key(),
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Releases owned by a label are paid into the label's token account,
+// only the label authority can move funds out of it
+pub fn handler(
+    ctx: Context<LabelWithdraw>,
+    amount: u64,
+) -> Result<()> {
+    let label = &ctx.accounts.label;
+    let label_id = label.label_id.to_le_bytes();
+    let seeds = &[
+        b"nina-label".as_ref(),
+        label.authority.as_ref(),
+        label_id.as_ref(),
+        &[label.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.label_token_account.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.label.to_account_info(),
+        },
+        signer,
+    );
+    transfer(cpi_ctx, amount)
+}
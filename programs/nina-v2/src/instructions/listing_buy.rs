@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Listing, ReleaseV2};
+use crate::instructions::listing_cancel::release_escrow;
+use crate::instructions::release_resale_approve::transfer_resale_payment;
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ListingBuy<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: This is safe because it is checked against listing
+    #[account(
+        mut,
+        constraint = seller.key() == listing.seller,
+    )]
+    pub seller: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"nina-listing", mint.key().as_ref(), seller.key().as_ref()],
+        bump = listing.bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    #[account(
+        constraint = release.key() == listing.release,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = buyer_payment_token_account.mint == release.payment_mint,
+      constraint = buyer_payment_token_account.owner == buyer.key(),
+    )]
+    pub buyer_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = seller_payment_token_account.mint == release.payment_mint,
+      constraint = seller_payment_token_account.owner == seller.key(),
+    )]
+    pub seller_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = royalty_token_account.key() == release.royalty_token_account,
+      constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<ListingBuy>,
+    price: u64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.buyer.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if price != ctx.accounts.listing.price {
+        return Err(error!(NinaError::ListingBuyWrongAmount));
+    }
+
+    transfer_resale_payment(
+        &ctx.accounts.release,
+        &ctx.accounts.buyer_payment_token_account,
+        &ctx.accounts.seller_payment_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.buyer,
+        &ctx.accounts.token_program,
+        price,
+    )?;
+
+    release_escrow(
+        &ctx.accounts.listing,
+        &ctx.accounts.mint,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.buyer_release_token_account,
+        &ctx.accounts.seller.to_account_info(),
+        &ctx.accounts.token_2022_program,
+    )
+}
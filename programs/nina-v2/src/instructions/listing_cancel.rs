@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account,
+        transfer_checked,
+        CloseAccount,
+        Token2022,
+        Mint,
+        TokenAccount,
+        TransferChecked,
+    },
+};
+
+use crate::state::Listing;
+
+#[derive(Accounts)]
+pub struct ListingCancel<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"nina-listing", mint.key().as_ref(), seller.key().as_ref()],
+        bump = listing.bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    #[account(
+      constraint = mint.key() == listing.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<ListingCancel>,
+) -> Result<()> {
+    release_escrow(
+        &ctx.accounts.listing,
+        &ctx.accounts.mint,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.seller_release_token_account,
+        &ctx.accounts.seller.to_account_info(),
+        &ctx.accounts.token_2022_program,
+    )
+}
+
+// Moves the escrowed token to `to` and returns the escrow account rent to the seller
+pub fn release_escrow<'info>(
+    listing: &Account<'info, Listing>,
+    mint: &InterfaceAccount<'info, Mint>,
+    escrow_token_account: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    seller: &AccountInfo<'info>,
+    token_2022_program: &Program<'info, Token2022>,
+) -> Result<()> {
+    let seeds = &[
+        b"nina-listing".as_ref(),
+        listing.mint.as_ref(),
+        listing.seller.as_ref(),
+        &[listing.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: escrow_token_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: listing.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    transfer_checked(cpi_ctx, 1, mint.decimals)?;
+
+    let cpi_accounts = CloseAccount {
+        account: escrow_token_account.to_account_info(),
+        destination: seller.clone(),
+        authority: listing.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    close_account(cpi_ctx)
+}
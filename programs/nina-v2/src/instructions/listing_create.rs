@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::spl_token_2022::extension::transfer_hook::TransferHook,
+    token_interface::{
+        transfer_checked,
+        Token2022,
+        Mint,
+        TokenAccount,
+        TransferChecked,
+    },
+};
+
+use crate::state::{Listing, ReleaseV2};
+use crate::utils::{get_mint_extension_data, id_account_key};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ListingCreate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub seller: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [b"nina-listing", mint.key().as_ref(), seller.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 113,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<ListingCreate>,
+    price: u64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.seller.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    // releases using this program as their transfer hook can't be moved into escrow by CPI,
+    // they are resold through release_resale_approve instead
+    if let Ok(transfer_hook) = get_mint_extension_data::<TransferHook>(&mut ctx.accounts.mint.to_account_info()) {
+        if Option::<Pubkey>::from(transfer_hook.program_id) == Some(crate::ID) {
+            return Err(error!(NinaError::ListingTransferHookRelease));
+        }
+    }
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.seller_release_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.escrow_token_account.to_account_info(),
+        authority: ctx.accounts.seller.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_2022_program.to_account_info(),
+        cpi_accounts,
+    );
+    transfer_checked(cpi_ctx, 1, ctx.accounts.mint.decimals)?;
+
+    let listing = &mut ctx.accounts.listing;
+    listing.seller = ctx.accounts.seller.key();
+    listing.release = ctx.accounts.release.key();
+    listing.mint = ctx.accounts.mint.key();
+    listing.price = price;
+    listing.bump = ctx.bumps.listing;
+
+    Ok(())
+}
@@ -3,9 +3,109 @@ pub mod release_purchase;
 pub mod release_init_and_purchase;
 pub mod release_update;
 pub mod release_close;
+pub mod release_purchase_with_voucher;
+pub mod release_airdrop;
+pub mod release_purchase_gift;
+pub mod bundle_init;
+pub mod bundle_purchase;
+pub mod album_init;
+pub mod album_add_release;
+pub mod album_purchase;
+pub mod release_transfer_hook_init;
+pub mod release_transfer_hook;
+pub mod release_resale_approve;
+pub mod listing_create;
+pub mod listing_cancel;
+pub mod listing_buy;
+pub mod release_mint_close;
+pub mod release_token_revoke;
+pub mod release_metadata_remove_key;
+pub mod release_update_partial;
+pub mod release_reveal_init;
+pub mod release_reveal;
+pub mod crowdfund_init;
+pub mod crowdfund_purchase;
+pub mod crowdfund_finalize;
+pub mod release_refund;
+pub mod release_preorder_init;
+pub mod release_preorder;
+pub mod release_fulfill_preorder;
+pub mod release_preorder_cancel;
+pub mod release_preorder_reclaim;
+pub mod hub_init;
+pub mod hub_add_release;
+pub mod hub_remove_release;
+pub mod hub_release_purchase;
+pub mod profile_init;
+pub mod profile_update;
+pub mod profile_verify;
+pub mod label_init;
+pub mod label_set_manager;
+pub mod label_withdraw;
+pub mod multisig_init;
+pub mod multisig_propose;
+pub mod multisig_approve;
+pub mod multisig_execute;
+pub mod release_tip;
+pub mod release_claim_configure;
+pub mod release_claim;
+pub mod release_tier_init;
+pub mod release_purchase_tier;
+pub mod release_numbered_init;
+pub mod release_purchase_numbered;
 
 pub use release_init_v2::*;
 pub use release_purchase::*;
 pub use release_init_and_purchase::*;
 pub use release_update::*;
-pub use release_close::*;
\ No newline at end of file
+pub use release_close::*;
+pub use release_purchase_with_voucher::*;
+pub use release_airdrop::*;
+pub use release_purchase_gift::*;
+pub use bundle_init::*;
+pub use bundle_purchase::*;
+pub use album_init::*;
+pub use album_add_release::*;
+pub use album_purchase::*;
+pub use release_transfer_hook_init::*;
+pub use release_transfer_hook::*;
+pub use release_resale_approve::*;
+pub use listing_create::*;
+pub use listing_cancel::*;
+pub use listing_buy::*;
+pub use release_mint_close::*;
+pub use release_token_revoke::*;
+pub use release_metadata_remove_key::*;
+pub use release_update_partial::*;
+pub use release_reveal_init::*;
+pub use release_reveal::*;
+pub use crowdfund_init::*;
+pub use crowdfund_purchase::*;
+pub use crowdfund_finalize::*;
+pub use release_refund::*;
+pub use release_preorder_init::*;
+pub use release_preorder::*;
+pub use release_fulfill_preorder::*;
+pub use release_preorder_cancel::*;
+pub use release_preorder_reclaim::*;
+pub use hub_init::*;
+pub use hub_add_release::*;
+pub use hub_remove_release::*;
+pub use hub_release_purchase::*;
+pub use profile_init::*;
+pub use profile_update::*;
+pub use profile_verify::*;
+pub use label_init::*;
+pub use label_set_manager::*;
+pub use label_withdraw::*;
+pub use multisig_init::*;
+pub use multisig_propose::*;
+pub use multisig_approve::*;
+pub use multisig_execute::*;
+pub use release_tip::*;
+pub use release_claim_configure::*;
+pub use release_claim::*;
+pub use release_tier_init::*;
+pub use release_purchase_tier::*;
+pub use release_numbered_init::*;
+pub use release_purchase_numbered::*;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Multisig, MultisigProposal};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct MultisigApprove<'info> {
+    pub member: Signer<'info>,
+    #[account(
+        seeds = [b"nina-multisig", multisig.release.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(
+        mut,
+        seeds = [b"nina-proposal", multisig.key().as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+}
+
+pub fn handler(
+    ctx: Context<MultisigApprove>,
+) -> Result<()> {
+    let member_index = ctx.accounts.multisig.member_index(ctx.accounts.member.key())
+        .ok_or(NinaError::MultisigNotMember)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.executed {
+        return Err(error!(NinaError::MultisigProposalExecuted));
+    }
+    proposal.approvals |= 1 << member_index;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint,
+    TokenAccount,
+};
+
+use crate::state::{Multisig, MultisigAction, MultisigProposal, ReleaseV2};
+use crate::instructions::release_init_v2::validate_total_supply_update;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct MultisigExecute<'info> {
+    #[account(
+        seeds = [b"nina-multisig", release.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(
+        mut,
+        seeds = [b"nina-proposal", multisig.key().as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+        constraint = release.authority == multisig.key(),
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    // only needed for payout proposals
+    #[account(
+        constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+// Anyone can execute a proposal once enough members have approved it
+pub fn handler(
+    ctx: Context<MultisigExecute>,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.executed {
+        return Err(error!(NinaError::MultisigProposalExecuted));
+    }
+    if proposal.approvals.count_ones() < u32::from(ctx.accounts.multisig.threshold) {
+        return Err(error!(NinaError::MultisigThresholdNotMet));
+    }
+
+    let release = &mut ctx.accounts.release;
+    match proposal.action.clone() {
+        MultisigAction::Update { price, total_supply } => {
+            if let Some(total_supply) = total_supply {
+                validate_total_supply_update(release, ctx.accounts.mint.supply, total_supply)?;
+                release.total_supply = total_supply;
+            }
+            if let Some(price) = price {
+                release.price = price;
+            }
+        }
+        MultisigAction::Payout { royalty_token_account } => {
+            match &ctx.accounts.royalty_token_account {
+                Some(account) if account.key() == royalty_token_account => {
+                    release.royalty_token_account = royalty_token_account;
+                }
+                _ => return Err(error!(NinaError::MultisigPayoutMismatch)),
+            }
+        }
+        MultisigAction::Close => {
+            release.total_supply = ctx.accounts.mint.supply;
+            release.open_edition = false;
+        }
+        MultisigAction::SetAuthority { authority } => {
+            release.authority = authority;
+        }
+    }
+
+    proposal.executed = true;
+
+    Ok(())
+}
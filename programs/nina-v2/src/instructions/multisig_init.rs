@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Multisig, ReleaseV2, MULTISIG_MAX_MEMBERS};
+use crate::utils::file_service_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct MultisigInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == release.authority,
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+        init,
+        seeds = [b"nina-multisig", release.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 374,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    pub system_program: Program<'info, System>,
+}
+
+// Hands the release over to an M-of-N multisig, which becomes release.authority
+pub fn handler(
+    ctx: Context<MultisigInit>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if members.is_empty()
+        || members.len() > MULTISIG_MAX_MEMBERS
+        || members.iter().enumerate().any(|(i, member)| members[..i].contains(member))
+    {
+        return Err(error!(NinaError::MultisigInvalidMembers));
+    }
+    if threshold == 0 || usize::from(threshold) > members.len() {
+        return Err(error!(NinaError::MultisigInvalidThreshold));
+    }
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.release = ctx.accounts.release.key();
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.bump = ctx.bumps.multisig;
+
+    ctx.accounts.release.authority = multisig.key();
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Multisig, MultisigAction, MultisigProposal};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct MultisigPropose<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-multisig", multisig.release.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(
+        init,
+        seeds = [b"nina-proposal", multisig.key().as_ref(), multisig.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = member,
+        space = 84,
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    pub system_program: Program<'info, System>,
+}
+
+// The proposing member's approval is counted straight away
+pub fn handler(
+    ctx: Context<MultisigPropose>,
+    action: MultisigAction,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let member_index = multisig.member_index(ctx.accounts.member.key())
+        .ok_or(NinaError::MultisigNotMember)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.proposal_id = multisig.proposal_count;
+    proposal.action = action;
+    proposal.approvals = 1 << member_index;
+
+    multisig.proposal_count = multisig.proposal_count
+        .checked_add(1)
+        .ok_or(NinaError::ArithmeticError)?;
+
+    Ok(())
+}
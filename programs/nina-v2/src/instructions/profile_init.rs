@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Profile, PROFILE_HANDLE_MAX_LENGTH};
+use crate::instructions::release_init_v2::validate_release_uri;
+use crate::utils::file_service_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ProfileInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"nina-profile", authority.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 314,
+    )]
+    pub profile: Account<'info, Profile>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProfileInit>,
+    handle: String,
+    uri: String,
+    payout_token_account: Option<Pubkey>,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_profile_handle(&handle)?;
+    validate_release_uri(&uri)?;
+
+    let profile = &mut ctx.accounts.profile;
+    profile.authority = ctx.accounts.authority.key();
+    profile.handle = handle;
+    profile.uri = uri;
+    profile.payout_token_account = payout_token_account;
+
+    Ok(())
+}
+
+pub fn validate_profile_handle(handle: &str) -> Result<()> {
+    if handle.is_empty() || handle.len() > PROFILE_HANDLE_MAX_LENGTH {
+        return Err(error!(NinaError::ProfileInvalidHandle));
+    }
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Profile;
+use crate::instructions::release_init_v2::validate_release_uri;
+use crate::instructions::profile_init::validate_profile_handle;
+
+#[derive(Accounts)]
+pub struct ProfileUpdate<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-profile", authority.key().as_ref()],
+        bump,
+    )]
+    pub profile: Account<'info, Profile>,
+}
+
+pub fn handler(
+    ctx: Context<ProfileUpdate>,
+    handle: String,
+    uri: String,
+    payout_token_account: Option<Pubkey>,
+) -> Result<()> {
+    validate_profile_handle(&handle)?;
+    validate_release_uri(&uri)?;
+
+    let profile = &mut ctx.accounts.profile;
+    // verification is for a handle, a new one has to be verified again
+    if profile.handle != handle {
+        profile.verified = false;
+    }
+    profile.handle = handle;
+    profile.uri = uri;
+    profile.payout_token_account = payout_token_account;
+
+    Ok(())
+}
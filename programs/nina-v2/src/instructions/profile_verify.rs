@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Profile;
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ProfileVerify<'info> {
+    #[account(
+        constraint = verifier.key() == id_account_key() @ NinaError::ProfileUnauthorizedVerifier,
+    )]
+    pub verifier: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-profile", profile.authority.as_ref()],
+        bump,
+    )]
+    pub profile: Account<'info, Profile>,
+}
+
+// Only the identity service can mark a profile as verified
+pub fn handler(
+    ctx: Context<ProfileVerify>,
+    verified: bool,
+) -> Result<()> {
+    ctx.accounts.profile.verified = verified;
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{AssociatedToken, get_associated_token_address_with_program_id},
+    token_interface::{
+        Token2022,
+        Mint,
+    },
+};
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_AIRDROP};
+use crate::instructions::release_purchase::{create_release_token_account, mint_release_token_to};
+use crate::events::ReleaseAirdropped;
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+#[instruction(release_signer_bump: u8)]
+pub struct ReleaseAirdrop<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub label: Option<Box<Account<'info, Label>>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// remaining accounts: [recipient, recipient_release_token_account] pairs
+pub fn handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ReleaseAirdrop<'info>>,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_AIRDROP,
+    )?;
+
+    if ctx.accounts.release.numbered {
+        return Err(error!(NinaError::ReleaseNumbered));
+    }
+
+    let recipient_accounts = ctx.remaining_accounts.chunks_exact(2);
+    if recipient_accounts.len() == 0 || !recipient_accounts.remainder().is_empty() {
+        return Err(error!(NinaError::ReleaseAirdropInvalidRemainingAccounts));
+    }
+
+    let airdrop_count = recipient_accounts.len() as u64;
+    let supply_after = ctx.accounts.mint.supply
+        .checked_add(ctx.accounts.release.preorders_outstanding)
+        .and_then(|reserved| reserved.checked_add(airdrop_count))
+        .ok_or(NinaError::ArithmeticError)?;
+    if !ctx.accounts.release.open_edition && supply_after > ctx.accounts.release.total_supply {
+        return Err(error!(NinaError::ReleaseAirdropExceedsSupply));
+    }
+
+    let mut recipients = Vec::with_capacity(airdrop_count as usize);
+    for accounts in recipient_accounts {
+        let recipient = &accounts[0];
+        let recipient_release_token_account = &accounts[1];
+
+        let expected_token_account = get_associated_token_address_with_program_id(
+            recipient.key,
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.token_2022_program.key(),
+        );
+        if recipient_release_token_account.key() != expected_token_account {
+            return Err(error!(NinaError::ReleaseAirdropWrongTokenAccount));
+        }
+
+        create_release_token_account(
+            ctx.accounts.payer.to_account_info(),
+            recipient.to_account_info(),
+            recipient_release_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.system_program,
+            &ctx.accounts.associated_token_program,
+            &ctx.accounts.token_2022_program,
+        )?;
+
+        mint_release_token_to(
+            ctx.accounts.mint.to_account_info(),
+            recipient_release_token_account.to_account_info(),
+            &ctx.accounts.release_signer,
+            &ctx.accounts.release,
+            &ctx.accounts.token_2022_program,
+            release_signer_bump,
+        )?;
+
+        recipients.push(recipient.key());
+    }
+
+    emit!(ReleaseAirdropped {
+        release: ctx.accounts.release.key(),
+        authority: ctx.accounts.authority.key(),
+        recipients,
+    });
+
+    Ok(())
+}
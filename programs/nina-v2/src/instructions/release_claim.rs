@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{ClaimConfig, ClaimRecord, ReleaseV2, VoucherRedemption};
+use crate::instructions::release_purchase::{validate_supply, mint_release_token};
+use crate::instructions::release_purchase_with_voucher::validate_voucher;
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+#[instruction(
+  release_signer_bump: u8,
+  voucher_expiry: i64,
+  voucher_nonce: u64,
+)]
+pub struct ReleaseClaim<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [b"nina-claim-config", release.key().as_ref()],
+        bump = claim_config.bump,
+    )]
+    pub claim_config: Box<Account<'info, ClaimConfig>>,
+    #[account(
+        init_if_needed,
+        seeds = [b"nina-claim", release.key().as_ref(), receiver.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 80,
+    )]
+    pub claim_record: Box<Account<'info, ClaimRecord>>,
+    // voucher accounts are only needed when the claim config requires a voucher
+    #[account(
+        init,
+        seeds = [b"nina-voucher", release.key().as_ref(), voucher_nonce.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 88,
+    )]
+    pub voucher_redemption: Option<Box<Account<'info, VoucherRedemption>>>,
+    /// CHECK: This is safe because the address is checked against the instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Free releases are claimed without any payment accounts, limited per wallet
+// and optionally gated behind a voucher signed for a price of 0
+pub fn handler(
+    ctx: Context<ReleaseClaim>,
+    release_signer_bump: u8,
+    voucher_expiry: i64,
+    voucher_nonce: u64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if ctx.accounts.release.price != 0 {
+        return Err(error!(NinaError::ReleaseNotFree));
+    }
+
+    let claim_config = &ctx.accounts.claim_config;
+    if claim_config.wallet_limit != 0 && ctx.accounts.claim_record.count >= claim_config.wallet_limit {
+        return Err(error!(NinaError::ReleaseClaimLimitReached));
+    }
+
+    if claim_config.voucher_required {
+        let (Some(voucher_redemption), Some(instructions)) = (
+            &mut ctx.accounts.voucher_redemption,
+            &ctx.accounts.instructions,
+        ) else {
+            return Err(error!(NinaError::ReleaseClaimVoucherRequired));
+        };
+        validate_voucher(
+            instructions,
+            &ctx.accounts.release,
+            &ctx.accounts.receiver.key(),
+            0,
+            voucher_expiry,
+            voucher_nonce,
+        )?;
+        voucher_redemption.release = ctx.accounts.release.key();
+        voucher_redemption.buyer = ctx.accounts.receiver.key();
+        voucher_redemption.nonce = voucher_nonce;
+        voucher_redemption.price = 0;
+    }
+
+    validate_supply(&ctx.accounts.release, &ctx.accounts.mint)?;
+
+    mint_release_token(
+        &ctx.accounts.mint,
+        &ctx.accounts.receiver_release_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )?;
+
+    let claim_record = &mut ctx.accounts.claim_record;
+    claim_record.release = ctx.accounts.release.key();
+    claim_record.claimant = ctx.accounts.receiver.key();
+    claim_record.count = claim_record.count.checked_add(1).ok_or(NinaError::ArithmeticError)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ClaimConfig, Label, ReleaseV2, LABEL_ROLE_PRICE};
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseClaimConfigure<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+        init_if_needed,
+        seeds = [b"nina-claim-config", release.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 50,
+    )]
+    pub claim_config: Box<Account<'info, ClaimConfig>>,
+    pub system_program: Program<'info, System>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+// Enables release_claim for a free release, or changes its anti-sybil settings
+pub fn handler(
+    ctx: Context<ReleaseClaimConfigure>,
+    wallet_limit: u64,
+    voucher_required: bool,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_PRICE,
+    )?;
+
+    let claim_config = &mut ctx.accounts.claim_config;
+    claim_config.release = ctx.accounts.release.key();
+    claim_config.wallet_limit = wallet_limit;
+    claim_config.voucher_required = voucher_required;
+    claim_config.bump = ctx.bumps.claim_config;
+
+    Ok(())
+}
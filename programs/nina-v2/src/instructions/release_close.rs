@@ -12,18 +12,15 @@ use anchor_spl::{
     },
 };
 
-use crate::state::ReleaseV2;
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_CLOSE};
 use crate::instructions::release_init_v2::update_mint_balance;
-use crate::utils::file_service_account_key;
+use crate::utils::{file_service_account_key, validate_release_authority};
 use crate::errors::NinaError;
 
 #[derive(Accounts)]
 pub struct ReleaseClose<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(
-        constraint = authority.key() == release.authority,
-    )]
     pub authority: Signer<'info>,
     /// CHECK: This is safe because it is derived from release which is checked above
     #[account(
@@ -42,6 +39,7 @@ pub struct ReleaseClose<'info> {
         constraint = mint.key() == release.mint,
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub label: Option<Box<Account<'info, Label>>>,
 }
 
 pub fn handler(
@@ -54,7 +52,15 @@ pub fn handler(
         }
     }
 
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_CLOSE,
+    )?;
+
     ctx.accounts.release.total_supply = ctx.accounts.mint.supply;
+    ctx.accounts.release.open_edition = false;
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{PreorderReceipt, ReleaseV2};
+use crate::instructions::release_purchase::mint_release_token;
+use crate::instructions::release_preorder::transfer_from_release_escrow;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseFulfillPreorder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: This is safe because it is checked against the preorder receipt
+    #[account(
+        mut,
+        constraint = buyer.key() == preorder_receipt.buyer,
+    )]
+    pub buyer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"nina-preorder", release.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub preorder_receipt: Box<Account<'info, PreorderReceipt>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = release_signer,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = royalty_token_account.key() == release.royalty_token_account,
+        constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Anyone can crank a pre-order once the release date has passed: the buyer
+// gets their copies and the escrowed payment moves to the royalty account
+pub fn handler(
+    ctx: Context<ReleaseFulfillPreorder>,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.release.preorder_cancelled {
+        return Err(error!(NinaError::ReleasePreorderCancelled));
+    }
+    if Clock::get()?.unix_timestamp < ctx.accounts.release.release_date {
+        return Err(error!(NinaError::ReleaseNotYetReleased));
+    }
+
+    let copies = ctx.accounts.preorder_receipt.copies;
+    for _ in 0..copies {
+        mint_release_token(
+            &ctx.accounts.mint,
+            &ctx.accounts.buyer_release_token_account,
+            &ctx.accounts.release_signer,
+            &ctx.accounts.release,
+            &ctx.accounts.token_2022_program,
+            release_signer_bump,
+        )?;
+    }
+
+    transfer_from_release_escrow(
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.token_program,
+        ctx.accounts.preorder_receipt.amount,
+        release_signer_bump,
+    )?;
+
+    let release = &mut ctx.accounts.release;
+    release.preorders_outstanding = release.preorders_outstanding
+        .checked_sub(copies)
+        .ok_or(NinaError::ArithmeticError)?;
+
+    Ok(())
+}
@@ -10,9 +10,25 @@ use anchor_spl::{
     token_2022::{MintTo, mint_to},
 };
 
-use crate::state::ReleaseV2;
-use crate::instructions::release_init_v2::{set_release_data, initialize_token_metadata, update_mint_balance};
-use crate::instructions::release_purchase::{validate_purchase, transfer_payment, transfer_crs, mint_release_token};
+use crate::state::{Profile, ReleaseProfile, ReleaseV2};
+use crate::instructions::release_init_v2::{
+    create_release_mint,
+    link_release_profile,
+    set_release_config,
+    set_release_data,
+    initialize_token_metadata,
+    set_token_metadata_fields,
+    update_mint_balance,
+    validate_release_metadata,
+    validate_total_supply,
+    ReleaseConfig,
+};
+use crate::instructions::release_purchase::{
+    transfer_payment,
+    transfer_crs,
+    create_release_token_account,
+    mint_release_token_to,
+};
 use crate::utils::file_service_account_key;
 use crate::errors::NinaError;
 #[derive(Accounts)]
@@ -47,16 +63,8 @@ pub struct ReleaseInitAndPurchase<'info> {
         bump,
     )]
     pub release_signer: UncheckedAccount<'info>,
-    #[account(
-        init,
-        payer = payer,
-        mint::token_program = token_2022_program,
-        mint::decimals = 0,
-        mint::authority = release_signer,
-        extensions::metadata_pointer::authority = release_signer,
-        extensions::metadata_pointer::metadata_address = mint,
-    )]
-    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub mint: Signer<'info>,
     pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
       mut,
@@ -71,14 +79,9 @@ pub struct ReleaseInitAndPurchase<'info> {
       associated_token::authority = authority,
   )]
   pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
-    #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::token_program = token_2022_program,
-        associated_token::mint = mint,
-        associated_token::authority = receiver,
-    )]
-    pub receiver_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is safe because it is created as the receiver's associated token account in the handler
+    #[account(mut)]
+    pub receiver_release_token_account: UncheckedAccount<'info>,
     ///TODO: CHECK THAT ADDRESS === EXPECTED CRS ADDRESS
     // #[account(
     //   mut,
@@ -89,8 +92,22 @@ pub struct ReleaseInitAndPurchase<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [b"nina-profile", authority.key().as_ref()],
+        bump,
+    )]
+    pub profile: Option<Box<Account<'info, Profile>>>,
+    #[account(
+        init,
+        seeds = [b"nina-release-profile", release.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 72,
+    )]
+    pub release_profile: Option<Box<Account<'info, ReleaseProfile>>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<ReleaseInitAndPurchase>,
     release_signer_bump: u8,
@@ -99,6 +116,7 @@ pub fn handler(
     symbol: String,
     total_supply: u64,
     price: u64,
+    config: ReleaseConfig,
 ) -> Result<()> {
 
     if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
@@ -108,9 +126,21 @@ pub fn handler(
         }
     }
 
+    validate_release_metadata(&name, &symbol, &uri)?;
+    validate_total_supply(total_supply, 0, config.open_edition)?;
+
+    create_release_mint(
+        &ctx.accounts.payer,
+        &ctx.accounts.mint,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.system_program,
+        &ctx.accounts.token_2022_program,
+        &config,
+    )?;
+
     initialize_token_metadata(
         &ctx.accounts.token_2022_program,
-        &ctx.accounts.mint,
+        &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.release,
         &ctx.accounts.release_signer,
         name,
@@ -119,10 +149,17 @@ pub fn handler(
         release_signer_bump,
     )?;
 
-    ctx.accounts.mint.reload()?;
+    set_token_metadata_fields(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &config.additional_metadata,
+        release_signer_bump,
+    )?;
 
     update_mint_balance(
-        &ctx.accounts.mint,
+        &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.payer,
         &ctx.accounts.system_program,
     )?;
@@ -131,18 +168,14 @@ pub fn handler(
         &mut ctx.accounts.release,
         &ctx.accounts.authority,
         &ctx.accounts.release_signer,
-        &ctx.accounts.mint,
+        &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.royalty_token_account,
         &ctx.accounts.payment_mint,
         total_supply,
         price,
     );
 
-    validate_purchase(
-        &ctx.accounts.release,
-        &ctx.accounts.mint,
-        price,
-    )?;
+    set_release_config(&mut ctx.accounts.release, &config)?;
 
     transfer_payment(
         &ctx.accounts.payment_token_account,
@@ -160,15 +193,29 @@ pub fn handler(
     //     price,
     // )?;
 
-    mint_release_token(
-        &ctx.accounts.mint,
-        &ctx.accounts.receiver_release_token_account,
+    create_release_token_account(
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.receiver.to_account_info(),
+        ctx.accounts.receiver_release_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.token_2022_program,
+    )?;
+
+    mint_release_token_to(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.receiver_release_token_account.to_account_info(),
         &ctx.accounts.release_signer,
         &ctx.accounts.release,
         &ctx.accounts.token_2022_program,
         release_signer_bump,
     )?;
-    
-    Ok(())
+
+    link_release_profile(
+        &ctx.accounts.release,
+        &ctx.accounts.profile,
+        &mut ctx.accounts.release_profile,
+    )
 }
     
\ No newline at end of file
@@ -1,23 +1,44 @@
 use anchor_lang::{
     prelude::*,
-    solana_program::entrypoint::ProgramResult
+    solana_program::entrypoint::ProgramResult,
+    system_program::{create_account, CreateAccount},
 };
 use anchor_spl::{
   associated_token::AssociatedToken,
-  token_2022::spl_token_2022::extension::{
-      group_member_pointer::GroupMemberPointer,
-      metadata_pointer::MetadataPointer,
-      mint_close_authority::MintCloseAuthority,
-      permanent_delegate::PermanentDelegate,
-      transfer_hook::TransferHook,
+  token_2022::spl_token_2022::{
+      extension::{
+          group_member_pointer::GroupMemberPointer,
+          metadata_pointer::MetadataPointer,
+          mint_close_authority::MintCloseAuthority,
+          permanent_delegate::PermanentDelegate,
+          transfer_hook::TransferHook,
+          ExtensionType,
+      },
+      state::Mint as MintState,
   },
   token_interface::{
-      spl_token_metadata_interface::state::TokenMetadata, 
+      spl_token_metadata_interface::state::{Field, TokenMetadata}, 
+      group_member_pointer_initialize,
+      initialize_mint2,
+      metadata_pointer_initialize,
+      mint_close_authority_initialize,
+      non_transferable_mint_initialize,
+      permanent_delegate_initialize,
       token_metadata_initialize,
+      token_metadata_update_field,
+      transfer_hook_initialize,
+      GroupMemberPointerInitialize,
+      InitializeMint2,
+      MetadataPointerInitialize,
       Mint,
+      MintCloseAuthorityInitialize,
+      NonTransferableMintInitialize,
+      PermanentDelegateInitialize,
       Token2022,
       TokenAccount,
       TokenMetadataInitialize,
+      TokenMetadataUpdateField,
+      TransferHookInitialize,
   },
   token::{
     Token
@@ -28,11 +49,13 @@ use spl_pod::optional_keys::OptionalNonZeroPubkey;
 use crate::{
   get_meta_list_size,
   get_mint_extension_data,
+  get_mint_token_metadata,
   update_account_lamports_to_minimum_balance,
   META_LIST_ACCOUNT_SEED,
 };
 
-use crate::state::ReleaseV2;
+use crate::state::{Profile, ReleaseProfile, ReleaseV2};
+use crate::instructions::release_purchase::BASIS_POINTS;
 use crate::utils::file_service_account_key;
 use crate::errors::NinaError;
 
@@ -46,6 +69,35 @@ pub struct ReleaseInitV2Args {
     pub release_signer_bump: u8,
 }
 
+pub const METADATA_FIELDS_MAX: usize = 10;
+pub const METADATA_FIELD_KEY_MAX_LENGTH: usize = 32;
+pub const METADATA_FIELD_VALUE_MAX_LENGTH: usize = 200;
+const METADATA_RESERVED_KEYS: [&str; 3] = ["name", "symbol", "uri"];
+pub const RELEASE_NAME_MAX_LENGTH: usize = 32;
+pub const RELEASE_SYMBOL_MAX_LENGTH: usize = 10;
+pub const RELEASE_URI_MAX_LENGTH: usize = 200;
+const RELEASE_URI_SCHEMES: [&str; 3] = ["https://", "ar://", "ipfs://"];
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct MetadataField {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default)]
+pub struct ReleaseConfig {
+    pub transfer_hook: bool,
+    pub resale_royalty: u64,
+    pub non_transferable: bool,
+    pub permanent_delegate: bool,
+    pub mint_close_authority: bool,
+    pub additional_metadata: Vec<MetadataField>,
+    pub open_edition: bool,
+    pub sale_end: Option<i64>,
+    pub referral_fee: u64,
+    pub tip_requires_holder: bool,
+}
+
 #[derive(Accounts)]
 #[instruction(uri: String, name: String, symbol: String, total_supply: u64, price: u64, release_signer_bump: u8)]
 pub struct ReleaseInitV2<'info> {
@@ -62,16 +114,8 @@ pub struct ReleaseInitV2<'info> {
         space = 232,
     )]
     pub release: Account<'info, ReleaseV2>,
-    #[account(
-        init,
-        payer = payer,
-        mint::token_program = token_2022_program,
-        mint::decimals = 0,
-        mint::authority = release_signer,
-        extensions::metadata_pointer::authority = release_signer,
-        extensions::metadata_pointer::metadata_address = mint,
-    )]
-    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub mint: Signer<'info>,
     /// CHECK: This is safe because it is derived from release which is checked above
     #[account(
         seeds = [release.key().as_ref()],
@@ -89,11 +133,190 @@ pub struct ReleaseInitV2<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [b"nina-profile", authority.key().as_ref()],
+        bump,
+    )]
+    pub profile: Option<Box<Account<'info, Profile>>>,
+    #[account(
+        init,
+        seeds = [b"nina-release-profile", release.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 72,
+    )]
+    pub release_profile: Option<Box<Account<'info, ReleaseProfile>>>,
+}
+
+pub fn create_release_mint<'info>(
+    payer: &Signer<'info>,
+    mint: &Signer<'info>,
+    release_signer: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    token_2022_program: &Program<'info, Token2022>,
+    config: &ReleaseConfig,
+) -> Result<()> {
+    let mut extensions = vec![
+        ExtensionType::MetadataPointer,
+        ExtensionType::GroupMemberPointer,
+    ];
+    if config.transfer_hook && config.non_transferable {
+        return Err(error!(NinaError::ReleaseNonTransferableTransferHook));
+    }
+    if config.transfer_hook {
+        extensions.push(ExtensionType::TransferHook);
+    }
+    if config.non_transferable {
+        extensions.push(ExtensionType::NonTransferable);
+    }
+    if config.permanent_delegate {
+        extensions.push(ExtensionType::PermanentDelegate);
+    }
+    if config.mint_close_authority {
+        extensions.push(ExtensionType::MintCloseAuthority);
+    }
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+
+    create_account(
+        CpiContext::new(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: mint.to_account_info(),
+            },
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_2022_program.key,
+    )?;
+
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_2022_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: token_2022_program.to_account_info(),
+                mint: mint.to_account_info(),
+            },
+        ),
+        Some(release_signer.key()),
+        Some(mint.key()),
+    )?;
+
+    group_member_pointer_initialize(
+        CpiContext::new(
+            token_2022_program.to_account_info(),
+            GroupMemberPointerInitialize {
+                token_program_id: token_2022_program.to_account_info(),
+                mint: mint.to_account_info(),
+            },
+        ),
+        Some(release_signer.key()),
+        Some(mint.key()),
+    )?;
+
+    if config.transfer_hook {
+        transfer_hook_initialize(
+            CpiContext::new(
+                token_2022_program.to_account_info(),
+                TransferHookInitialize {
+                    token_program_id: token_2022_program.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            Some(release_signer.key()),
+            Some(crate::ID),
+        )?;
+    }
+
+    if config.non_transferable {
+        non_transferable_mint_initialize(
+            CpiContext::new(
+                token_2022_program.to_account_info(),
+                NonTransferableMintInitialize {
+                    token_program_id: token_2022_program.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+        )?;
+    }
+
+    if config.permanent_delegate {
+        permanent_delegate_initialize(
+            CpiContext::new(
+                token_2022_program.to_account_info(),
+                PermanentDelegateInitialize {
+                    token_program_id: token_2022_program.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            &release_signer.key(),
+        )?;
+    }
+
+    if config.mint_close_authority {
+        mint_close_authority_initialize(
+            CpiContext::new(
+                token_2022_program.to_account_info(),
+                MintCloseAuthorityInitialize {
+                    token_program_id: token_2022_program.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            Some(&release_signer.key()),
+        )?;
+    }
+
+    initialize_mint2(
+        CpiContext::new(
+            token_2022_program.to_account_info(),
+            InitializeMint2 {
+                mint: mint.to_account_info(),
+            },
+        ),
+        0,
+        &release_signer.key(),
+        None,
+    )
+}
+
+pub fn set_release_config<'info>(
+    release: &mut Account<'info, ReleaseV2>,
+    config: &ReleaseConfig,
+) -> Result<()> {
+    if config.resale_royalty > BASIS_POINTS {
+        return Err(error!(NinaError::ReleaseInvalidResaleRoyalty));
+    }
+    release.resale_royalty = config.resale_royalty;
+    release.open_edition = config.open_edition;
+    release.sale_end = validate_sale_end(config.sale_end)?;
+    validate_referral_fee(config.referral_fee)?;
+    release.referral_fee = config.referral_fee;
+    release.tip_requires_holder = config.tip_requires_holder;
+
+    Ok(())
+}
+
+pub fn validate_referral_fee(referral_fee: u64) -> Result<()> {
+    if referral_fee > BASIS_POINTS {
+        return Err(error!(NinaError::ReleaseInvalidReferralFee));
+    }
+    Ok(())
+}
+
+// returns the value stored on ReleaseV2, 0 when the sale never ends
+pub fn validate_sale_end(sale_end: Option<i64>) -> Result<i64> {
+    match sale_end {
+        Some(sale_end) if sale_end <= Clock::get()?.unix_timestamp => {
+            Err(error!(NinaError::ReleaseInvalidSaleEnd))
+        }
+        Some(sale_end) => Ok(sale_end),
+        None => Ok(0),
+    }
 }
 
 pub fn initialize_token_metadata<'info>(
     token_2022_program: &Program<'info, Token2022>,
-    mint: &InterfaceAccount<'info, Mint>,
+    mint: &AccountInfo<'info>,
     release: &Account<'info, ReleaseV2>,
     release_signer: &UncheckedAccount<'info>,
     name: String,
@@ -123,8 +346,140 @@ pub fn initialize_token_metadata<'info>(
     token_metadata_initialize(cpi_ctx, name, symbol, uri)
 }
 
+pub fn validate_release_name(name: &str) -> Result<()> {
+    if name.len() > RELEASE_NAME_MAX_LENGTH {
+        return Err(error!(NinaError::ReleaseNameTooLong));
+    }
+    Ok(())
+}
+
+pub fn validate_release_symbol(symbol: &str) -> Result<()> {
+    if symbol.len() > RELEASE_SYMBOL_MAX_LENGTH {
+        return Err(error!(NinaError::ReleaseSymbolTooLong));
+    }
+    Ok(())
+}
+
+pub fn validate_release_uri(uri: &str) -> Result<()> {
+    if uri.len() > RELEASE_URI_MAX_LENGTH {
+        return Err(error!(NinaError::ReleaseUriTooLong));
+    }
+    if !RELEASE_URI_SCHEMES.iter().any(|scheme| uri.starts_with(scheme)) {
+        return Err(error!(NinaError::ReleaseInvalidUriScheme));
+    }
+    Ok(())
+}
+
+pub fn validate_release_metadata(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> Result<()> {
+    validate_release_name(name)?;
+    validate_release_symbol(symbol)?;
+    validate_release_uri(uri)
+}
+
+// minted is the current mint supply, zero for releases that are being created.
+// Open editions ignore total_supply so it isn't checked.
+pub fn validate_total_supply(total_supply: u64, minted: u64, open_edition: bool) -> Result<()> {
+    if open_edition {
+        return Ok(());
+    }
+    if total_supply == 0 {
+        return Err(error!(NinaError::ReleaseTotalSupplyZero));
+    }
+    if total_supply < minted {
+        return Err(error!(NinaError::ReleaseTotalSupplyBelowMinted));
+    }
+    Ok(())
+}
+
+// Checks a new total supply against copies minted or reserved by pre-orders;
+// numbered releases keep the edition size they were created with
+pub fn validate_total_supply_update(release: &ReleaseV2, mint_supply: u64, total_supply: u64) -> Result<()> {
+    if release.numbered {
+        if total_supply != release.total_supply {
+            return Err(error!(NinaError::ReleaseNumberedSupplyFixed));
+        }
+        return Ok(());
+    }
+
+    validate_total_supply(
+        total_supply,
+        mint_supply.saturating_add(release.preorders_outstanding),
+        release.open_edition,
+    )
+}
+
+pub fn validate_metadata_fields(
+    existing_fields: &[(String, String)],
+    fields: &[MetadataField],
+) -> Result<()> {
+    let mut keys: Vec<&str> = existing_fields.iter().map(|(key, _)| key.as_str()).collect();
+    for field in fields {
+        if field.key.is_empty()
+            || field.key.len() > METADATA_FIELD_KEY_MAX_LENGTH
+            || METADATA_RESERVED_KEYS.contains(&field.key.as_str())
+        {
+            return Err(error!(NinaError::ReleaseMetadataInvalidKey));
+        }
+        if field.value.len() > METADATA_FIELD_VALUE_MAX_LENGTH {
+            return Err(error!(NinaError::ReleaseMetadataValueTooLong));
+        }
+        if !keys.contains(&field.key.as_str()) {
+            keys.push(field.key.as_str());
+        }
+    }
+    if keys.len() > METADATA_FIELDS_MAX {
+        return Err(error!(NinaError::ReleaseMetadataTooManyFields));
+    }
+
+    Ok(())
+}
+
+// Writes additional key/value fields to the mint's token metadata, the caller
+// is responsible for topping up the mint with update_mint_balance afterwards
+pub fn set_token_metadata_fields<'info>(
+    token_2022_program: &Program<'info, Token2022>,
+    mint: &AccountInfo<'info>,
+    release: &Account<'info, ReleaseV2>,
+    release_signer: &UncheckedAccount<'info>,
+    fields: &[MetadataField],
+    release_signer_bump: u8,
+) -> Result<()> {
+    if fields.is_empty() {
+        return Ok(());
+    }
+
+    let token_metadata = get_mint_token_metadata(mint)?;
+    validate_metadata_fields(&token_metadata.additional_metadata, fields)?;
+
+    let seeds = &[
+        release.to_account_info().key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    for field in fields {
+        let cpi_accounts = TokenMetadataUpdateField {
+            program_id: token_2022_program.to_account_info(),
+            metadata: mint.to_account_info(),
+            update_authority: release_signer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_2022_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        token_metadata_update_field(cpi_ctx, Field::Key(field.key.clone()), field.value.clone())?;
+    }
+
+    Ok(())
+}
+
 pub fn update_mint_balance<'info>(
-    mint: &InterfaceAccount<'info, Mint>,
+    mint: &AccountInfo<'info>,
     payer: &Signer<'info>,
     system_program: &Program<'info, System>,
 ) -> Result<()> {
@@ -135,11 +490,29 @@ pub fn update_mint_balance<'info>(
     )
 }
 
+// Optionally links the release to its authority's profile
+pub fn link_release_profile<'info>(
+    release: &Account<'info, ReleaseV2>,
+    profile: &Option<Box<Account<'info, Profile>>>,
+    release_profile: &mut Option<Box<Account<'info, ReleaseProfile>>>,
+) -> Result<()> {
+    match (profile, release_profile) {
+        (Some(profile), Some(release_profile)) => {
+            release_profile.release = release.key();
+            release_profile.profile = profile.key();
+        }
+        (None, None) => {}
+        _ => return Err(error!(NinaError::ReleaseProfileMismatch)),
+    }
+
+    Ok(())
+}
+
 pub fn set_release_data<'info>(
     release: &mut Account<'info, ReleaseV2>,
     authority: &UncheckedAccount<'info>,
     release_signer: &UncheckedAccount<'info>,
-    mint: &InterfaceAccount<'info, Mint>,
+    mint: &AccountInfo<'info>,
     royalty_token_account: &InterfaceAccount<'info, TokenAccount>,
     payment_mint: &InterfaceAccount<'info, Mint>,
     total_supply: u64,
@@ -154,6 +527,7 @@ pub fn set_release_data<'info>(
     release.price = price;
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<ReleaseInitV2>,
     uri: String,
@@ -162,6 +536,7 @@ pub fn handler(
     total_supply: u64,
     price: u64,
     release_signer_bump: u8,
+    config: ReleaseConfig,
 ) -> Result<()> {
     if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
         #[cfg(feature = "is-test")]
@@ -170,9 +545,21 @@ pub fn handler(
         }
     }
 
+    validate_release_metadata(&name, &symbol, &uri)?;
+    validate_total_supply(total_supply, 0, config.open_edition)?;
+
+    create_release_mint(
+        &ctx.accounts.payer,
+        &ctx.accounts.mint,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.system_program,
+        &ctx.accounts.token_2022_program,
+        &config,
+    )?;
+
     initialize_token_metadata(
         &ctx.accounts.token_2022_program,
-        &ctx.accounts.mint,
+        &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.release,
         &ctx.accounts.release_signer,
         name,
@@ -180,11 +567,18 @@ pub fn handler(
         uri,
         release_signer_bump,
     )?;
-    
-    ctx.accounts.mint.reload()?;
+
+    set_token_metadata_fields(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &config.additional_metadata,
+        release_signer_bump,
+    )?;
     
     update_mint_balance(
-        &ctx.accounts.mint,
+        &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.payer,
         &ctx.accounts.system_program,
     )?;
@@ -193,12 +587,18 @@ pub fn handler(
         &mut ctx.accounts.release,
         &ctx.accounts.authority,
         &ctx.accounts.release_signer,
-        &ctx.accounts.mint,
+        &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.royalty_token_account,
         &ctx.accounts.payment_mint,
         total_supply,
         price,
     );
-    
-    Ok(())
+
+    set_release_config(&mut ctx.accounts.release, &config)?;
+
+    link_release_profile(
+        &ctx.accounts.release,
+        &ctx.accounts.profile,
+        &mut ctx.accounts.release_profile,
+    )
 }
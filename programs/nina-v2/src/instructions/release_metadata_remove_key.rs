@@ -0,0 +1,81 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::invoke_signed,
+};
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::instruction::remove_key,
+    Mint,
+    Token2022,
+};
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_UPDATE_METADATA};
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseMetadataRemoveKey<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: This is safe because it is checked against release
+    #[account(
+        constraint = release_signer.key() == release.release_signer,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub label: Option<Box<Account<'info, Label>>>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseMetadataRemoveKey>,
+    key: String,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_UPDATE_METADATA,
+    )?;
+
+    let seeds = &[
+        ctx.accounts.release.to_account_info().key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let ix = remove_key(
+        ctx.accounts.token_2022_program.key,
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.release_signer.key(),
+        key,
+        false,
+    );
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.release_signer.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    Ok(())
+}
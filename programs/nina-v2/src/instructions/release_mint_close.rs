@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::extension::mint_close_authority::MintCloseAuthority,
+    token_interface::{
+        close_account,
+        CloseAccount,
+        Mint,
+        Token2022,
+    },
+};
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_CLOSE};
+use crate::utils::{file_service_account_key, get_mint_extension_data, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseMintClose<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseMintClose>,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_CLOSE,
+    )?;
+
+    if ctx.accounts.mint.supply != 0 {
+        return Err(error!(NinaError::ReleaseMintSupplyNotZero));
+    }
+    // pre-order payments sit in escrow until the release account fulfils or refunds them
+    let release = &ctx.accounts.release;
+    if release.preorders_outstanding > 0 || (release.release_date != 0 && !release.preorder_cancelled) {
+        return Err(error!(NinaError::ReleasePreordersOutstanding));
+    }
+
+    let mint_close_authority = get_mint_extension_data::<MintCloseAuthority>(&mut ctx.accounts.mint.to_account_info())
+        .map_err(|_| NinaError::ReleaseMintNotClosable)?;
+    if Option::<Pubkey>::from(mint_close_authority.close_authority) != Some(ctx.accounts.release_signer.key()) {
+        return Err(error!(NinaError::ReleaseMintNotClosable));
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.mint.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: ctx.accounts.release_signer.to_account_info(),
+    };
+
+    let release_key = ctx.accounts.release.key();
+    let seeds = &[
+        release_key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    close_account(cpi_ctx)
+}
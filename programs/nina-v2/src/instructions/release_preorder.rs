@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Token, Transfer},
+    token_interface::{
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{PreorderReceipt, ReleaseV2};
+use crate::instructions::release_purchase::{
+    validate_edition_available,
+    transfer_payment,
+};
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleasePreorder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        seeds = [b"nina-preorder", release.key().as_ref(), receiver.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 88,
+    )]
+    pub preorder_receipt: Box<Account<'info, PreorderReceipt>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = payment_token_account.mint == release.payment_mint,
+        constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = release_signer,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Pays into the release_signer escrow and reserves a copy to be minted
+// by release_fulfill_preorder once the release date has passed
+pub fn handler(
+    ctx: Context<ReleasePreorder>,
+    amount: u64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if ctx.accounts.release.preorder_cancelled {
+        return Err(error!(NinaError::ReleasePreorderCancelled));
+    }
+    if Clock::get()?.unix_timestamp >= ctx.accounts.release.release_date {
+        return Err(error!(NinaError::ReleasePreorderClosed));
+    }
+    if amount != ctx.accounts.release.price {
+        return Err(error!(NinaError::ReleasePurchaseWrongAmount));
+    }
+    validate_edition_available(&ctx.accounts.release, &ctx.accounts.mint)?;
+
+    transfer_payment(
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.receiver,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let release = &mut ctx.accounts.release;
+    release.preorders_outstanding = release.preorders_outstanding
+        .checked_add(1)
+        .ok_or(NinaError::ArithmeticError)?;
+
+    let preorder_receipt = &mut ctx.accounts.preorder_receipt;
+    preorder_receipt.release = release.key();
+    preorder_receipt.buyer = ctx.accounts.receiver.key();
+    preorder_receipt.amount = preorder_receipt.amount.checked_add(amount).ok_or(NinaError::ArithmeticError)?;
+    preorder_receipt.copies = preorder_receipt.copies.checked_add(1).ok_or(NinaError::ArithmeticError)?;
+
+    Ok(())
+}
+
+pub fn transfer_from_release_escrow<'info>(
+    release: &Account<'info, ReleaseV2>,
+    release_signer: &UncheckedAccount<'info>,
+    escrow_token_account: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+    release_signer_bump: u8,
+) -> Result<()> {
+    let seeds = &[
+        release.to_account_info().key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+            from: escrow_token_account.to_account_info(),
+            to: to.to_account_info(),
+            authority: release_signer.to_account_info(),
+        },
+        signer,
+    );
+    transfer(cpi_ctx, amount)
+}
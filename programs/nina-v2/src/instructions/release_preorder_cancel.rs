@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_PRICE};
+use crate::utils::validate_release_authority;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleasePreorderCancel<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+// Cancelling stops all sales of the release and lets pre-order buyers
+// reclaim their payment with release_preorder_reclaim
+pub fn handler(
+    ctx: Context<ReleasePreorderCancel>,
+) -> Result<()> {
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_PRICE,
+    )?;
+
+    let release = &mut ctx.accounts.release;
+    if release.release_date == 0 || release.preorder_cancelled {
+        return Err(error!(NinaError::ReleasePreorderClosed));
+    }
+    if Clock::get()?.unix_timestamp >= release.release_date {
+        return Err(error!(NinaError::ReleasePreorderClosed));
+    }
+
+    release.preorder_cancelled = true;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_PRICE};
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleasePreorderInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = release_signer,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+pub fn handler(
+    ctx: Context<ReleasePreorderInit>,
+    release_date: i64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_PRICE,
+    )?;
+
+    let release = &mut ctx.accounts.release;
+    if release.release_date != 0 || release.preorder_cancelled {
+        return Err(error!(NinaError::ReleasePreorderExists));
+    }
+    if release.crowdfunding {
+        return Err(error!(NinaError::ReleaseCrowdfunding));
+    }
+    if release_date <= Clock::get()?.unix_timestamp {
+        return Err(error!(NinaError::ReleaseInvalidReleaseDate));
+    }
+
+    release.release_date = release_date;
+
+    Ok(())
+}
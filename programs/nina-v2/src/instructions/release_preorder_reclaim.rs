@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{PreorderReceipt, ReleaseV2};
+use crate::instructions::release_preorder::transfer_from_release_escrow;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleasePreorderReclaim<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"nina-preorder", release.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub preorder_receipt: Box<Account<'info, PreorderReceipt>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = payment_token_account.mint == release.payment_mint,
+        constraint = payment_token_account.owner == buyer.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = release_signer,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ReleasePreorderReclaim>,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if !ctx.accounts.release.preorder_cancelled {
+        return Err(error!(NinaError::ReleasePreorderNotCancelled));
+    }
+
+    transfer_from_release_escrow(
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.token_program,
+        ctx.accounts.preorder_receipt.amount,
+        release_signer_bump,
+    )?;
+
+    let release = &mut ctx.accounts.release;
+    release.preorders_outstanding = release.preorders_outstanding
+        .checked_sub(ctx.accounts.preorder_receipt.copies)
+        .ok_or(NinaError::ArithmeticError)?;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{AssociatedToken, Create, create_idempotent},
     token::{Token, Transfer},
     token_interface::{
         Token2022,
@@ -12,9 +12,10 @@ use anchor_spl::{
 
 use crate::state::ReleaseV2;
 use crate::errors::NinaError;
+use crate::events::ReleasePurchased;
 use crate::utils::id_account_key;
 
-const BASIS_POINTS: u64 = 1_000_000;
+pub const BASIS_POINTS: u64 = 1_000_000;
 const ONE_USDC: u64 = 10_000_000;
 const TEN_PERCENT: u64 = 100_000;
 
@@ -79,6 +80,12 @@ pub struct ReleasePurchase<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+      mut,
+      constraint = referrer_token_account.mint == release.payment_mint,
+      constraint = referrer_token_account.owner != receiver.key() @ NinaError::ReleaseSelfReferral,
+    )]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 pub fn handler<'c: 'info, 'info>(
@@ -94,14 +101,33 @@ pub fn handler<'c: 'info, 'info>(
     }
 
     validate_purchase(&ctx.accounts.release, &ctx.accounts.mint, amount)?;
-    
-    transfer_payment(
-        &ctx.accounts.payment_token_account,
-        &ctx.accounts.royalty_token_account,
-        &ctx.accounts.receiver,
-        &ctx.accounts.token_program,
-        amount,
-    )?;
+
+    let referral_amount = match &ctx.accounts.referrer_token_account {
+        Some(referrer_token_account) => {
+            let referral_amount = calculate_fee(amount, ctx.accounts.release.referral_fee)?;
+            if referral_amount > 0 {
+                transfer_payment(
+                    &ctx.accounts.payment_token_account,
+                    referrer_token_account,
+                    &ctx.accounts.receiver,
+                    &ctx.accounts.token_program,
+                    referral_amount,
+                )?;
+            }
+            referral_amount
+        }
+        None => 0,
+    };
+
+    if amount > referral_amount {
+        transfer_payment(
+            &ctx.accounts.payment_token_account,
+            &ctx.accounts.royalty_token_account,
+            &ctx.accounts.receiver,
+            &ctx.accounts.token_program,
+            amount - referral_amount,
+        )?;
+    }
     
     // transfer_crs(
     //     &ctx.accounts.payment_token_account,
@@ -119,7 +145,17 @@ pub fn handler<'c: 'info, 'info>(
         &ctx.accounts.token_2022_program,
         release_signer_bump,
     )?;
-    
+
+    emit!(ReleasePurchased {
+        release: ctx.accounts.release.key(),
+        receiver: ctx.accounts.receiver.key(),
+        amount,
+        referrer: ctx.accounts.referrer_token_account
+            .as_ref()
+            .map(|referrer_token_account| referrer_token_account.owner),
+        referral_amount,
+    });
+
     Ok(())
 }
 
@@ -132,13 +168,72 @@ pub fn validate_purchase<'info>(
         return Err(error!(NinaError::ReleasePurchaseWrongAmount));
     }
 
-    if mint.supply >= release.total_supply {
+    validate_supply(release, mint)
+}
+
+pub fn validate_supply<'info>(
+    release: &Account<'info, ReleaseV2>,
+    mint: &InterfaceAccount<'info, Mint>,
+) -> Result<()> {
+    validate_release_on_sale(release)?;
+    validate_edition_available(release, mint)
+}
+
+// Checks the release is out and being sold directly rather than
+// through a crowdfund or a cancelled pre-order
+pub fn validate_release_on_sale(release: &ReleaseV2) -> Result<()> {
+    if release.crowdfunding {
+        return Err(error!(NinaError::ReleaseCrowdfunding));
+    }
+    if release.preorder_cancelled {
+        return Err(error!(NinaError::ReleasePreorderCancelled));
+    }
+    if Clock::get()?.unix_timestamp < release.release_date {
+        return Err(error!(NinaError::ReleaseNotYetReleased));
+    }
+
+    Ok(())
+}
+
+pub fn validate_sale_open(release: &ReleaseV2) -> Result<()> {
+    if release.sale_end != 0 && Clock::get()?.unix_timestamp >= release.sale_end {
+        return Err(error!(NinaError::ReleaseSaleEnded));
+    }
+
+    Ok(())
+}
+
+// Checks another copy can be minted: the sale hasn't ended and,
+// unless the release is an open edition, it isn't sold out once
+// outstanding pre-orders are counted
+pub fn validate_edition_available<'info>(
+    release: &Account<'info, ReleaseV2>,
+    mint: &InterfaceAccount<'info, Mint>,
+) -> Result<()> {
+    if release.numbered {
+        return Err(error!(NinaError::ReleaseNumbered));
+    }
+    validate_sale_open(release)?;
+
+    let reserved = mint.supply
+        .checked_add(release.preorders_outstanding)
+        .ok_or(NinaError::ArithmeticError)?;
+    if !release.open_edition && reserved >= release.total_supply {
         return Err(error!(NinaError::ReleasePurchaseSoldOut));
     }
 
     Ok(())
 }
 
+// Portion of amount owed for a fee out of BASIS_POINTS
+pub fn calculate_fee(amount: u64, fee: u64) -> Result<u64> {
+    u128::from(amount)
+        .checked_mul(u128::from(fee))
+        .and_then(|fee_amount| fee_amount.checked_div(u128::from(BASIS_POINTS)))
+        .and_then(|fee_amount| u64::try_from(fee_amount).ok())
+        .ok_or(error!(NinaError::ArithmeticError))
+}
+
 pub fn transfer_payment<'info>(
     payment_token_account: &InterfaceAccount<'info, TokenAccount>,
     royalty_token_account: &InterfaceAccount<'info, TokenAccount>,
@@ -146,6 +241,11 @@ pub fn transfer_payment<'info>(
     token_program: &Program<'info, Token>,
     amount: u64,
 ) -> Result<()> {
+    // free releases are minted without a zero-amount transfer
+    if amount == 0 {
+        return Ok(());
+    }
+
     let cpi_accounts = Transfer {
         from: payment_token_account.to_account_info(),
         to: royalty_token_account.to_account_info(),
@@ -167,10 +267,28 @@ pub fn mint_release_token<'info>(
     release: &Account<'info, ReleaseV2>,
     token_2022_program: &Program<'info, Token2022>,
     release_signer_bump: u8,
+) -> Result<()> {
+    mint_release_token_to(
+        mint.to_account_info(),
+        receiver_release_token_account.to_account_info(),
+        release_signer,
+        release,
+        token_2022_program,
+        release_signer_bump,
+    )
+}
+
+pub fn mint_release_token_to<'info>(
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    release_signer: &UncheckedAccount<'info>,
+    release: &Account<'info, ReleaseV2>,
+    token_2022_program: &Program<'info, Token2022>,
+    release_signer_bump: u8,
 ) -> Result<()> {
     let cpi_accounts_mint_to = MintTo {
-        mint: mint.to_account_info(),
-        to: receiver_release_token_account.to_account_info(),
+        mint,
+        to,
         authority: release_signer.to_account_info(),
     };
 
@@ -189,6 +307,32 @@ pub fn mint_release_token<'info>(
     mint_to(cpi_ctx_mint_to, 1)
 }
 
+pub fn create_release_token_account<'info>(
+    payer: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+    release_token_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    token_2022_program: &Program<'info, Token2022>,
+) -> Result<()> {
+    let cpi_accounts = Create {
+        payer,
+        associated_token: release_token_account,
+        authority: owner,
+        mint,
+        system_program: system_program.to_account_info(),
+        token_program: token_2022_program.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new(
+        associated_token_program.to_account_info(),
+        cpi_accounts,
+    );
+
+    create_idempotent(cpi_ctx)
+}
+
 pub fn transfer_crs<'info>(
     payment_token_account: &InterfaceAccount<'info, TokenAccount>,
     crs_token_account: &InterfaceAccount<'info, TokenAccount>,
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::ReleaseV2;
+use crate::instructions::release_purchase::{validate_purchase, transfer_payment, mint_release_token};
+use crate::events::ReleaseGifted;
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+pub const GIFT_MESSAGE_MAX_LENGTH: usize = 280;
+
+#[derive(Accounts)]
+#[instruction(
+  amount: u64,
+  release_signer_bump: u8,
+)]
+pub struct ReleasePurchaseGift<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub buyer: Signer<'info>,
+    /// CHECK: can be any account, the gift does not need the receiver's signature
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+      mut,
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = payment_token_account.mint == release.payment_mint,
+      constraint = payment_token_account.owner == buyer.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = royalty_token_account.key() == release.royalty_token_account,
+      constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ReleasePurchaseGift<'info>>,
+    amount: u64,
+    release_signer_bump: u8,
+    message: Option<String>,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.buyer.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if let Some(message) = &message {
+        if message.len() > GIFT_MESSAGE_MAX_LENGTH {
+            return Err(error!(NinaError::ReleaseGiftMessageTooLong));
+        }
+    }
+
+    validate_purchase(&ctx.accounts.release, &ctx.accounts.mint, amount)?;
+
+    transfer_payment(
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.buyer,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    mint_release_token(
+        &ctx.accounts.mint,
+        &ctx.accounts.receiver_release_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )?;
+
+    emit!(ReleaseGifted {
+        release: ctx.accounts.release.key(),
+        buyer: ctx.accounts.buyer.key(),
+        receiver: ctx.accounts.receiver.key(),
+        amount,
+        message,
+    });
+
+    Ok(())
+}
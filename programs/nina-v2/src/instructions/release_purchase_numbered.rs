@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_2022::{
+        SetAuthority,
+        set_authority,
+        spl_token_2022::instruction::AuthorityType,
+    },
+    token_interface::{
+        token_member_initialize,
+        Mint,
+        Token2022,
+        TokenAccount,
+        TokenMemberInitialize,
+    },
+};
+use spl_token_group_interface::state::TokenGroup;
+
+use crate::state::ReleaseV2;
+use crate::events::ReleaseEditionPurchased;
+use crate::instructions::release_init_v2::{
+    initialize_token_metadata,
+    set_token_metadata_fields,
+    update_mint_balance,
+    MetadataField,
+};
+use crate::instructions::release_purchase::{
+    mint_release_token,
+    transfer_payment,
+    validate_release_on_sale,
+    validate_sale_open,
+};
+use crate::utils::{get_mint_extension_data, get_mint_token_metadata, id_account_key};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+#[instruction(
+  amount: u64,
+  release_signer_bump: u8,
+)]
+pub struct ReleasePurchaseNumbered<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub receiver: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+      mut,
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        mint::token_program = token_2022_program,
+        mint::decimals = 0,
+        mint::authority = release_signer,
+        extensions::metadata_pointer::authority = release_signer,
+        extensions::metadata_pointer::metadata_address = edition_mint,
+        extensions::group_member_pointer::authority = release_signer,
+        extensions::group_member_pointer::member_address = edition_mint,
+    )]
+    pub edition_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = payment_token_account.mint == release.payment_mint,
+      constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = royalty_token_account.key() == release.royalty_token_account,
+      constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = edition_mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_edition_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Mints a single-copy edition that joins the release group; the group's size
+// after joining is the edition number
+pub fn handler(
+    ctx: Context<ReleasePurchaseNumbered>,
+    amount: u64,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if !ctx.accounts.release.numbered {
+        return Err(error!(NinaError::ReleaseNotNumbered));
+    }
+    if amount != ctx.accounts.release.price {
+        return Err(error!(NinaError::ReleasePurchaseWrongAmount));
+    }
+    validate_release_on_sale(&ctx.accounts.release)?;
+    validate_sale_open(&ctx.accounts.release)?;
+
+    let group = get_mint_extension_data::<TokenGroup>(&mut ctx.accounts.mint.to_account_info())?;
+    let sold = u64::from(group.size);
+    if sold >= ctx.accounts.release.total_supply {
+        return Err(error!(NinaError::ReleasePurchaseSoldOut));
+    }
+    let edition = sold.checked_add(1).ok_or(NinaError::ArithmeticError)?;
+
+    transfer_payment(
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.receiver,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let token_metadata = get_mint_token_metadata(&ctx.accounts.mint.to_account_info())?;
+    initialize_token_metadata(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.edition_mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        format!("{} #{}", token_metadata.name, edition),
+        token_metadata.symbol,
+        token_metadata.uri,
+        release_signer_bump,
+    )?;
+
+    set_token_metadata_fields(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.edition_mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &[
+            MetadataField {
+                key: "release".to_string(),
+                value: ctx.accounts.mint.key().to_string(),
+            },
+            MetadataField {
+                key: "edition".to_string(),
+                value: edition.to_string(),
+            },
+        ],
+        release_signer_bump,
+    )?;
+
+    let release_key = ctx.accounts.release.key();
+    let seeds = &[
+        release_key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TokenMemberInitialize {
+        program_id: ctx.accounts.token_2022_program.to_account_info(),
+        member: ctx.accounts.edition_mint.to_account_info(),
+        member_mint: ctx.accounts.edition_mint.to_account_info(),
+        member_mint_authority: ctx.accounts.release_signer.to_account_info(),
+        group: ctx.accounts.mint.to_account_info(),
+        group_update_authority: ctx.accounts.release_signer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token_member_initialize(cpi_ctx)?;
+
+    update_mint_balance(
+        &ctx.accounts.edition_mint.to_account_info(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    mint_release_token(
+        &ctx.accounts.edition_mint,
+        &ctx.accounts.receiver_edition_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )?;
+
+    // no more copies of this edition can ever be minted
+    let cpi_accounts = SetAuthority {
+        current_authority: ctx.accounts.release_signer.to_account_info(),
+        account_or_mint: ctx.accounts.edition_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+
+    emit!(ReleaseEditionPurchased {
+        release: ctx.accounts.release.key(),
+        receiver: ctx.accounts.receiver.key(),
+        mint: ctx.accounts.edition_mint.key(),
+        edition,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{ReleaseTier, ReleaseV2};
+use crate::instructions::release_purchase::{
+    mint_release_token,
+    transfer_payment,
+    validate_release_on_sale,
+    validate_sale_open,
+};
+use crate::utils::id_account_key;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+#[instruction(
+  tier_index: u8,
+  amount: u64,
+  release_signer_bump: u8,
+)]
+pub struct ReleasePurchaseTier<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub receiver: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"nina-tier", release.key().as_ref(), &[tier_index]],
+        bump,
+    )]
+    pub release_tier: Box<Account<'info, ReleaseTier>>,
+    #[account(
+      mut,
+      constraint = tier_mint.key() == release_tier.mint,
+    )]
+    pub tier_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = payment_token_account.mint == release.payment_mint,
+      constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = royalty_token_account.key() == release.royalty_token_account,
+      constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = tier_mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_tier_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Tiers share the release's sale window but have their own price and supply
+pub fn handler(
+    ctx: Context<ReleasePurchaseTier>,
+    _tier_index: u8,
+    amount: u64,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if amount != ctx.accounts.release_tier.price {
+        return Err(error!(NinaError::ReleasePurchaseWrongAmount));
+    }
+    validate_release_on_sale(&ctx.accounts.release)?;
+    validate_sale_open(&ctx.accounts.release)?;
+    if ctx.accounts.tier_mint.supply >= ctx.accounts.release_tier.total_supply {
+        return Err(error!(NinaError::ReleasePurchaseSoldOut));
+    }
+
+    transfer_payment(
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.receiver,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    mint_release_token(
+        &ctx.accounts.tier_mint,
+        &ctx.accounts.receiver_tier_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )
+}
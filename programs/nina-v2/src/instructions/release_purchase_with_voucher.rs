@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{ReleaseV2, VoucherRedemption};
+use crate::instructions::release_purchase::{validate_supply, transfer_payment, mint_release_token};
+use crate::errors::NinaError;
+use crate::utils::{id_account_key, load_ed25519_signed_message};
+
+#[derive(Accounts)]
+#[instruction(
+  price: u64,
+  expiry: i64,
+  nonce: u64,
+  release_signer_bump: u8,
+)]
+pub struct ReleasePurchaseWithVoucher<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [b"nina-voucher", release.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 88,
+    )]
+    pub voucher_redemption: Account<'info, VoucherRedemption>,
+    #[account(
+      mut,
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = payment_token_account.mint == release.payment_mint,
+      constraint = payment_token_account.owner == receiver.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = royalty_token_account.key() == release.royalty_token_account,
+      constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = receiver,
+    )]
+    pub receiver_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is safe because the address is checked against the instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// release (32) | buyer (32) | price (8) | expiry (8) | nonce (8), integers little endian
+pub fn voucher_message(
+    release: &Pubkey,
+    buyer: &Pubkey,
+    price: u64,
+    expiry: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(88);
+    message.extend_from_slice(release.as_ref());
+    message.extend_from_slice(buyer.as_ref());
+    message.extend_from_slice(&price.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+pub fn validate_voucher(
+    instructions: &UncheckedAccount,
+    release: &Account<ReleaseV2>,
+    buyer: &Pubkey,
+    price: u64,
+    expiry: i64,
+    nonce: u64,
+) -> Result<()> {
+    if Clock::get()?.unix_timestamp > expiry {
+        return Err(error!(NinaError::VoucherExpired));
+    }
+
+    let (signer, message) = load_ed25519_signed_message(&instructions.to_account_info())?;
+    if signer != release.authority && signer != id_account_key() {
+        return Err(error!(NinaError::VoucherUnauthorizedSigner));
+    }
+
+    if message != voucher_message(&release.key(), buyer, price, expiry, nonce) {
+        return Err(error!(NinaError::VoucherSignatureInvalid));
+    }
+
+    Ok(())
+}
+
+pub fn handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ReleasePurchaseWithVoucher<'info>>,
+    price: u64,
+    expiry: i64,
+    nonce: u64,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.receiver.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_voucher(
+        &ctx.accounts.instructions,
+        &ctx.accounts.release,
+        &ctx.accounts.receiver.key(),
+        price,
+        expiry,
+        nonce,
+    )?;
+
+    validate_supply(&ctx.accounts.release, &ctx.accounts.mint)?;
+
+    if price > 0 {
+        transfer_payment(
+            &ctx.accounts.payment_token_account,
+            &ctx.accounts.royalty_token_account,
+            &ctx.accounts.receiver,
+            &ctx.accounts.token_program,
+            price,
+        )?;
+    }
+
+    mint_release_token(
+        &ctx.accounts.mint,
+        &ctx.accounts.receiver_release_token_account,
+        &ctx.accounts.release_signer,
+        &ctx.accounts.release,
+        &ctx.accounts.token_2022_program,
+        release_signer_bump,
+    )?;
+
+    let voucher_redemption = &mut ctx.accounts.voucher_redemption;
+    voucher_redemption.release = ctx.accounts.release.key();
+    voucher_redemption.buyer = ctx.accounts.receiver.key();
+    voucher_redemption.nonce = nonce;
+    voucher_redemption.price = price;
+
+    Ok(())
+}
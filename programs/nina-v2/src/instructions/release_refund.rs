@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        burn,
+        Burn,
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Crowdfund, CrowdfundContribution, ReleaseV2};
+use crate::instructions::crowdfund_finalize::transfer_from_escrow;
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseRefund<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"nina-crowdfund", release.key().as_ref()],
+        bump = crowdfund.bump,
+    )]
+    pub crowdfund: Box<Account<'info, Crowdfund>>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"nina-contribution", crowdfund.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub contribution: Box<Account<'info, CrowdfundContribution>>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = payment_token_account.mint == release.payment_mint,
+        constraint = payment_token_account.owner == buyer.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = crowdfund,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Once a crowdfund has missed its goal by the deadline, buyers burn the copies
+// they bought and get their payment back from the escrow
+pub fn handler(
+    ctx: Context<ReleaseRefund>,
+) -> Result<()> {
+    if Clock::get()?.unix_timestamp < ctx.accounts.crowdfund.deadline {
+        return Err(error!(NinaError::CrowdfundNotEnded));
+    }
+    if ctx.accounts.crowdfund.finalized || ctx.accounts.crowdfund.goal_met() {
+        return Err(error!(NinaError::CrowdfundGoalMet));
+    }
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_2022_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.buyer_release_token_account.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        },
+    );
+    burn(cpi_ctx, ctx.accounts.contribution.copies)?;
+
+    transfer_from_escrow(
+        &ctx.accounts.crowdfund,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.token_program,
+        ctx.accounts.contribution.amount,
+    )?;
+
+    Ok(())
+}
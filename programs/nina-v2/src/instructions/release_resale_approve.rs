@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{
+        Token2022,
+        Mint,
+        TokenAccount,
+    },
+};
+
+use crate::state::{ReleaseV2, TransferApproval};
+use crate::instructions::release_purchase::{transfer_payment, BASIS_POINTS};
+use crate::utils::{id_account_key, APPROVE_ACCOUNT_SEED};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseResaleApprove<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub seller: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Box<Account<'info, ReleaseV2>>,
+    #[account(
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = buyer_payment_token_account.mint == release.payment_mint,
+      constraint = buyer_payment_token_account.owner == buyer.key(),
+    )]
+    pub buyer_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = seller_payment_token_account.mint == release.payment_mint,
+      constraint = seller_payment_token_account.owner == seller.key(),
+    )]
+    pub seller_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = royalty_token_account.key() == release.royalty_token_account,
+      constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      associated_token::token_program = token_2022_program,
+      associated_token::mint = mint,
+      associated_token::authority = seller,
+    )]
+    pub seller_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_2022_program,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_release_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub transfer_approval: Account<'info, TransferApproval>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Pays the seller and the release royalty, then approves a single token moving from
+// seller to buyer. The seller signs the transfer_checked that follows in the same
+// transaction, which the transfer hook only lets through while this approval is open.
+pub fn handler(
+    ctx: Context<ReleaseResaleApprove>,
+    price: u64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.buyer.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    transfer_resale_payment(
+        &ctx.accounts.release,
+        &ctx.accounts.buyer_payment_token_account,
+        &ctx.accounts.seller_payment_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.buyer,
+        &ctx.accounts.token_program,
+        price,
+    )?;
+
+    let transfer_approval = &mut ctx.accounts.transfer_approval;
+    transfer_approval.source = ctx.accounts.seller_release_token_account.key();
+    transfer_approval.destination = ctx.accounts.buyer_release_token_account.key();
+    transfer_approval.amount = 1;
+
+    Ok(())
+}
+
+// Splits a resale price between the release royalty account and the seller
+pub fn transfer_resale_payment<'info>(
+    release: &ReleaseV2,
+    buyer_payment_token_account: &InterfaceAccount<'info, TokenAccount>,
+    seller_payment_token_account: &InterfaceAccount<'info, TokenAccount>,
+    royalty_token_account: &InterfaceAccount<'info, TokenAccount>,
+    buyer: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+    price: u64,
+) -> Result<()> {
+    let royalty = u128::from(price)
+        .checked_mul(u128::from(release.resale_royalty))
+        .and_then(|royalty| royalty.checked_div(u128::from(BASIS_POINTS)))
+        .and_then(|royalty| u64::try_from(royalty).ok())
+        .ok_or(NinaError::ReleaseInvalidResaleRoyalty)?;
+
+    if royalty > 0 {
+        transfer_payment(
+            buyer_payment_token_account,
+            royalty_token_account,
+            buyer,
+            token_program,
+            royalty,
+        )?;
+    }
+
+    if price > royalty {
+        transfer_payment(
+            buyer_payment_token_account,
+            seller_payment_token_account,
+            buyer,
+            token_program,
+            price - royalty,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::hash,
+};
+use anchor_spl::token_interface::{
+    Token2022,
+    Mint,
+    spl_token_metadata_interface::state::Field,
+    TokenMetadataUpdateField,
+    token_metadata_update_field,
+};
+
+use crate::state::{Label, ReleaseV2, RevealCommitment, LABEL_ROLE_UPDATE_METADATA};
+use crate::events::ReleaseRevealed;
+use crate::instructions::release_init_v2::{update_mint_balance, validate_release_uri};
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseReveal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: This is safe because it is checked against release
+    #[account(
+        constraint = release_signer.key() == release.release_signer,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"nina-reveal", release.key().as_ref()],
+        bump,
+    )]
+    pub reveal_commitment: Account<'info, RevealCommitment>,
+    pub system_program: Program<'info, System>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseReveal>,
+    uri: String,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_UPDATE_METADATA,
+    )?;
+
+    if hash(uri.as_bytes()).to_bytes() != ctx.accounts.reveal_commitment.uri_hash {
+        return Err(error!(NinaError::ReleaseRevealUriMismatch));
+    }
+    validate_release_uri(&uri)?;
+
+    let cpi_accounts = TokenMetadataUpdateField {
+        program_id: ctx.accounts.token_2022_program.to_account_info(),
+        metadata: ctx.accounts.mint.to_account_info(),
+        update_authority: ctx.accounts.release_signer.to_account_info(),
+    };
+    let seeds = &[
+        ctx.accounts.release.to_account_info().key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token_metadata_update_field(cpi_ctx, Field::Uri, uri.clone())?;
+
+    update_mint_balance(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    ctx.accounts.release.reveal_pending = false;
+
+    emit!(ReleaseRevealed {
+        release: ctx.accounts.release.key(),
+        uri,
+    });
+
+    Ok(())
+}
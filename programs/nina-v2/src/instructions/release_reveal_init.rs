@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{Label, ReleaseV2, RevealCommitment, LABEL_ROLE_UPDATE_METADATA};
+use crate::utils::{file_service_account_key, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseRevealInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    #[account(
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [b"nina-reveal", release.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 72,
+    )]
+    pub reveal_commitment: Account<'info, RevealCommitment>,
+    pub system_program: Program<'info, System>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+// Commits to the final uri while the release is sold with placeholder metadata
+pub fn handler(
+    ctx: Context<ReleaseRevealInit>,
+    uri_hash: [u8; 32],
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_UPDATE_METADATA,
+    )?;
+
+    let reveal_commitment = &mut ctx.accounts.reveal_commitment;
+    reveal_commitment.release = ctx.accounts.release.key();
+    reveal_commitment.uri_hash = uri_hash;
+    ctx.accounts.release.reveal_pending = true;
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_token_2022::extension::ExtensionType,
+    Mint,
+    Token2022,
+};
+
+use crate::state::{Label, ReleaseTier, ReleaseV2, LABEL_ROLE_PRICE, LABEL_ROLE_UPDATE_METADATA};
+use crate::instructions::release_init_v2::{
+    initialize_token_metadata,
+    set_token_metadata_fields,
+    update_mint_balance,
+    validate_release_metadata,
+    validate_total_supply,
+    MetadataField,
+};
+use crate::utils::{file_service_account_key, get_mint_extension_types, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ReleaseTierInitArgs {
+    pub tier_index: u8,
+    pub uri: String,
+    pub name: String,
+    pub symbol: String,
+    pub price: u64,
+    pub total_supply: u64,
+    pub release_signer_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ReleaseTierInitArgs)]
+pub struct ReleaseTierInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [b"nina-tier", release.key().as_ref(), &[args.tier_index]],
+        bump,
+        payer = payer,
+        space = 89,
+    )]
+    pub release_tier: Box<Account<'info, ReleaseTier>>,
+    #[account(
+        init,
+        payer = payer,
+        mint::token_program = token_2022_program,
+        mint::decimals = 0,
+        mint::authority = release_signer,
+        extensions::metadata_pointer::authority = release_signer,
+        extensions::metadata_pointer::metadata_address = tier_mint,
+    )]
+    pub tier_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+// Adds a tier with its own mint, price and supply; the tier mint's metadata
+// points back at the release mint so wallets can group tiers under it
+pub fn handler(
+    ctx: Context<ReleaseTierInit>,
+    args: ReleaseTierInitArgs,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_PRICE | LABEL_ROLE_UPDATE_METADATA,
+    )?;
+
+    // tier mints are plain copies, so releases relying on transfer restrictions
+    // or authority extensions can't be split into tiers without escaping them
+    let restricted = get_mint_extension_types(&ctx.accounts.mint.to_account_info())?
+        .into_iter()
+        .any(|extension| matches!(
+            extension,
+            ExtensionType::TransferHook
+                | ExtensionType::NonTransferable
+                | ExtensionType::PermanentDelegate
+                | ExtensionType::MintCloseAuthority
+        ));
+    if restricted {
+        return Err(error!(NinaError::ReleaseTierUnsupportedExtensions));
+    }
+
+    validate_release_metadata(&args.name, &args.symbol, &args.uri)?;
+    validate_total_supply(args.total_supply, 0, false)?;
+
+    initialize_token_metadata(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.tier_mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        args.name,
+        args.symbol,
+        args.uri,
+        args.release_signer_bump,
+    )?;
+
+    set_token_metadata_fields(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.tier_mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &[
+            MetadataField {
+                key: "release".to_string(),
+                value: ctx.accounts.release.mint.to_string(),
+            },
+            MetadataField {
+                key: "tier".to_string(),
+                value: args.tier_index.to_string(),
+            },
+        ],
+        args.release_signer_bump,
+    )?;
+
+    update_mint_balance(
+        &ctx.accounts.tier_mint.to_account_info(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    let release_tier = &mut ctx.accounts.release_tier;
+    release_tier.release = ctx.accounts.release.key();
+    release_tier.mint = ctx.accounts.tier_mint.key();
+    release_tier.tier_index = args.tier_index;
+    release_tier.price = args.price;
+    release_tier.total_supply = args.total_supply;
+
+    Ok(())
+}
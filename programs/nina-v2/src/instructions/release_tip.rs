@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{
+        Mint,
+        TokenAccount,
+    },
+};
+
+use spl_token_group_interface::state::TokenGroupMember;
+
+use crate::state::{ReleaseTier, ReleaseV2};
+use crate::instructions::release_purchase::transfer_payment;
+use crate::events::ReleaseTipped;
+use crate::utils::{get_mint_extension_data, id_account_key};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseTip<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tipper: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", release.mint.as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    #[account(
+        constraint = payment_mint.key() == release.payment_mint,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = payment_token_account.mint == release.payment_mint,
+        constraint = payment_token_account.owner == tipper.key(),
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = royalty_token_account.key() == release.royalty_token_account,
+        constraint = royalty_token_account.mint == release.payment_mint,
+    )]
+    pub royalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    // only needed when the release requires tippers to hold it
+    #[account(
+        constraint = tipper_release_token_account.owner == tipper.key(),
+    )]
+    pub tipper_release_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // a tier of the release, when the tipper holds its tier mint instead
+    pub release_tier: Option<Box<Account<'info, ReleaseTier>>>,
+    // a numbered edition mint, when the tipper holds one of those instead
+    pub holder_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseTip>,
+    amount: u64,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.tipper.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != id_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    if amount == 0 {
+        return Err(error!(NinaError::ReleaseTipZero));
+    }
+    if ctx.accounts.release.tip_requires_holder {
+        match &ctx.accounts.tipper_release_token_account {
+            Some(tipper_release_token_account)
+                if tipper_release_token_account.amount > 0
+                    && holds_release(
+                        &ctx.accounts.release,
+                        tipper_release_token_account.mint,
+                        &ctx.accounts.release_tier,
+                        &ctx.accounts.holder_mint,
+                    ) => {}
+            _ => return Err(error!(NinaError::ReleaseTipRequiresHolder)),
+        }
+    }
+
+    transfer_payment(
+        &ctx.accounts.payment_token_account,
+        &ctx.accounts.royalty_token_account,
+        &ctx.accounts.tipper,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    emit!(ReleaseTipped {
+        release: ctx.accounts.release.key(),
+        tipper: ctx.accounts.tipper.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+// Tier mints are recorded on the release's tiers, and numbered editions can only
+// join the release group with the release signer's signature
+fn holds_release(
+    release: &Account<ReleaseV2>,
+    held_mint: Pubkey,
+    release_tier: &Option<Box<Account<ReleaseTier>>>,
+    holder_mint: &Option<Box<InterfaceAccount<Mint>>>,
+) -> bool {
+    if held_mint == release.mint {
+        return true;
+    }
+    if let Some(release_tier) = release_tier {
+        if release_tier.release == release.key() && release_tier.mint == held_mint {
+            return true;
+        }
+    }
+    match holder_mint {
+        Some(holder_mint) if holder_mint.key() == held_mint => {
+            get_mint_extension_data::<TokenGroupMember>(&mut holder_mint.to_account_info())
+                .map(|member| member.group == release.mint)
+                .unwrap_or(false)
+        }
+        _ => false,
+    }
+}
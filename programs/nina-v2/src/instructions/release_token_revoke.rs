@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate,
+    token_interface::{
+        burn,
+        Burn,
+        Mint,
+        Token2022,
+        TokenAccount,
+    },
+};
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_CLOSE};
+use crate::utils::{file_service_account_key, get_mint_extension_data, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseTokenRevoke<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+        seeds = [release.key().as_ref()],
+        bump,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_2022_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub label: Option<Box<Account<'info, Label>>>,
+}
+
+// Burns every release token held by token_account, using release_signer as the
+// mint's permanent delegate
+pub fn handler(
+    ctx: Context<ReleaseTokenRevoke>,
+    release_signer_bump: u8,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        LABEL_ROLE_CLOSE,
+    )?;
+
+    let permanent_delegate = get_mint_extension_data::<PermanentDelegate>(&mut ctx.accounts.mint.to_account_info())
+        .map_err(|_| NinaError::ReleaseRevokeNotEnabled)?;
+    if Option::<Pubkey>::from(permanent_delegate.delegate) != Some(ctx.accounts.release_signer.key()) {
+        return Err(error!(NinaError::ReleaseRevokeNotEnabled));
+    }
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.release_signer.to_account_info(),
+    };
+
+    let release_key = ctx.accounts.release.key();
+    let seeds = &[
+        release_key.as_ref(),
+        &[release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_2022_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    burn(cpi_ctx, ctx.accounts.token_account.amount)
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint,
+    TokenAccount,
+};
+
+use crate::state::TransferApproval;
+use crate::utils::{
+    check_token_account_is_transferring,
+    APPROVE_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED,
+};
+use crate::errors::NinaError;
+
+// Accounts are laid out as Token-2022 passes them to the transfer hook execute instruction
+#[derive(Accounts)]
+pub struct ReleaseTransferHook<'info> {
+    #[account(
+        token::mint = mint,
+    )]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        token::mint = mint,
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: can be any account, the owner has already been checked by Token-2022
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: This is safe because it is derived from mint
+    #[account(
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub transfer_approval: Account<'info, TransferApproval>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseTransferHook>,
+    amount: u64,
+) -> Result<()> {
+    check_token_account_is_transferring(&ctx.accounts.source_token_account.to_account_info())?;
+
+    let transfer_approval = &mut ctx.accounts.transfer_approval;
+    if transfer_approval.amount != amount
+        || transfer_approval.source != ctx.accounts.source_token_account.key()
+        || transfer_approval.destination != ctx.accounts.destination_token_account.key()
+    {
+        return Err(error!(NinaError::ReleaseTransferNotApproved));
+    }
+
+    // approvals are single use
+    transfer_approval.source = Pubkey::default();
+    transfer_approval.destination = Pubkey::default();
+    transfer_approval.amount = 0;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::extension::transfer_hook::TransferHook,
+    token_interface::Mint,
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::state::{ReleaseV2, TransferApproval};
+use crate::utils::{
+    file_service_account_key,
+    get_meta_list,
+    get_meta_list_size,
+    get_mint_extension_data,
+    APPROVE_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED,
+};
+use crate::errors::NinaError;
+
+#[derive(Accounts)]
+pub struct ReleaseTransferHookInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == release.authority,
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"nina-release", mint.key().as_ref()],
+        bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    #[account(
+        constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 112,
+    )]
+    pub transfer_approval: Account<'info, TransferApproval>,
+    /// CHECK: This is safe because it is derived from mint and written as an extra account meta list below
+    #[account(
+        init,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = get_meta_list_size(Some(transfer_approval.key())),
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseTransferHookInit>,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    let transfer_hook = get_mint_extension_data::<TransferHook>(&mut ctx.accounts.mint.to_account_info())
+        .map_err(|_| NinaError::ReleaseTransferHookNotEnabled)?;
+    if Option::<Pubkey>::from(transfer_hook.program_id) != Some(crate::ID) {
+        return Err(error!(NinaError::ReleaseTransferHookNotEnabled));
+    }
+
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+        &get_meta_list(Some(ctx.accounts.transfer_approval.key())),
+    )?;
+
+    ctx.accounts.transfer_approval.mint = ctx.accounts.mint.key();
+
+    Ok(())
+}
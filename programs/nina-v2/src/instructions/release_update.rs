@@ -12,9 +12,15 @@ use anchor_spl::{
     },
 };
 
-use crate::state::ReleaseV2;
-use crate::instructions::release_init_v2::update_mint_balance;
-use crate::utils::file_service_account_key;
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_PRICE, LABEL_ROLE_UPDATE_METADATA};
+use crate::instructions::release_init_v2::{
+    set_token_metadata_fields,
+    update_mint_balance,
+    validate_release_metadata,
+    validate_total_supply_update,
+    MetadataField,
+};
+use crate::utils::{file_service_account_key, get_mint_token_metadata, validate_release_authority};
 use crate::errors::NinaError;
 
 #[derive(Accounts)]
@@ -53,8 +59,11 @@ pub struct ReleaseUpdate<'info> {
     pub mint: Box<InterfaceAccount<'info, Mint>>,
     pub system_program: Program<'info, System>,
     pub token_2022_program: Program<'info, Token2022>,
+    pub label: Option<Box<Account<'info, Label>>>,
+    pub manager: Option<Signer<'info>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
   ctx: Context<ReleaseUpdate>,
   uri: String,
@@ -63,9 +72,24 @@ pub fn handler(
   release_signer_bump: u8,
   price: u64,
   total_supply: u64,
+  additional_metadata: Vec<MetadataField>,
 ) -> Result<()> {
 
-  if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+  // a label manager signs for the label release authority
+  let signer = match &ctx.accounts.manager {
+      Some(manager) => {
+          validate_release_authority(
+              &ctx.accounts.release,
+              manager.key(),
+              &ctx.accounts.label,
+              LABEL_ROLE_UPDATE_METADATA | LABEL_ROLE_PRICE,
+          )?;
+          manager.key()
+      }
+      None => ctx.accounts.authority.key(),
+  };
+
+  if ctx.accounts.payer.key() != signer {
       
       #[cfg(feature = "is-test")]
       if ctx.accounts.payer.key() != file_service_account_key() {
@@ -73,6 +97,13 @@ pub fn handler(
       }
   }
 
+    validate_release_metadata(&name, &symbol, &uri)?;
+    if ctx.accounts.release.reveal_pending
+        && uri != get_mint_token_metadata(&ctx.accounts.mint.to_account_info())?.uri {
+        return Err(error!(NinaError::ReleaseRevealPending));
+    }
+    validate_total_supply_update(&ctx.accounts.release, ctx.accounts.mint.supply, total_supply)?;
+
     let cpi_accounts_uri = TokenMetadataUpdateField {
         program_id: ctx.accounts.token_2022_program.to_account_info(),
         metadata: ctx.accounts.mint.to_account_info(),
@@ -119,10 +150,19 @@ pub fn handler(
     token_metadata_update_field(cpi_ctx_name, Field::Name, name)?;
     token_metadata_update_field(cpi_ctx_symbol, Field::Symbol, symbol)?;
 
+    set_token_metadata_fields(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &additional_metadata,
+        release_signer_bump,
+    )?;
+
     ctx.accounts.mint.reload()?;
     
     update_mint_balance(
-        &ctx.accounts.mint,
+        &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.payer,
         &ctx.accounts.system_program,
     )?;
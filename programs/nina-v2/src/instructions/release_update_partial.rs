@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Token2022,
+    Mint,
+    spl_token_metadata_interface::state::Field,
+    TokenMetadataUpdateField,
+    token_metadata_update_field,
+};
+
+use crate::state::{Label, ReleaseV2, LABEL_ROLE_PRICE, LABEL_ROLE_UPDATE_METADATA};
+use crate::instructions::release_init_v2::{
+    set_token_metadata_fields,
+    update_mint_balance,
+    validate_release_name,
+    validate_release_symbol,
+    validate_release_uri,
+    validate_total_supply_update,
+    validate_sale_end,
+    validate_referral_fee,
+    MetadataField,
+};
+use crate::utils::{file_service_account_key, get_mint_token_metadata, validate_release_authority};
+use crate::errors::NinaError;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ReleaseUpdatePartialArgs {
+    pub uri: Option<String>,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub price: Option<u64>,
+    pub total_supply: Option<u64>,
+    pub additional_metadata: Option<Vec<MetadataField>>,
+    // Some(0) removes the sale end
+    pub sale_end: Option<i64>,
+    pub referral_fee: Option<u64>,
+    pub tip_requires_holder: Option<bool>,
+    pub release_signer_bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseUpdatePartial<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: This is safe because it is derived from release which is checked above
+    #[account(
+      mut,
+      constraint = release_signer.key() == release.release_signer,
+    )]
+    pub release_signer: UncheckedAccount<'info>,
+    #[account(
+      mut,
+      seeds = [b"nina-release", mint.key().as_ref()],
+      bump,
+    )]
+    pub release: Account<'info, ReleaseV2>,
+    #[account(
+      mut,
+      constraint = mint.key() == release.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub label: Option<Box<Account<'info, Label>>>,
+    pub system_program: Program<'info, System>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+// Only fields that are set and differ from what is already on chain are written
+pub fn handler(
+    ctx: Context<ReleaseUpdatePartial>,
+    args: ReleaseUpdatePartialArgs,
+) -> Result<()> {
+    if ctx.accounts.payer.key() != ctx.accounts.authority.key() {
+        #[cfg(feature = "is-test")]
+        if ctx.accounts.payer.key() != file_service_account_key() {
+            return Err(error!(NinaError::DelegatedPayerMismatch));
+        }
+    }
+
+    let mut roles = 0;
+    if args.uri.is_some() || args.name.is_some() || args.symbol.is_some() || args.additional_metadata.is_some() {
+        roles |= LABEL_ROLE_UPDATE_METADATA;
+    }
+    if args.price.is_some() || args.total_supply.is_some() || args.sale_end.is_some() || args.referral_fee.is_some() || args.tip_requires_holder.is_some() {
+        roles |= LABEL_ROLE_PRICE;
+    }
+    validate_release_authority(
+        &ctx.accounts.release,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.label,
+        roles,
+    )?;
+
+    if let Some(uri) = &args.uri {
+        validate_release_uri(uri)?;
+    }
+    if let Some(name) = &args.name {
+        validate_release_name(name)?;
+    }
+    if let Some(symbol) = &args.symbol {
+        validate_release_symbol(symbol)?;
+    }
+    if let Some(total_supply) = args.total_supply {
+        validate_total_supply_update(&ctx.accounts.release, ctx.accounts.mint.supply, total_supply)?;
+    }
+    if let Some(referral_fee) = args.referral_fee {
+        validate_referral_fee(referral_fee)?;
+    }
+
+    let token_metadata = get_mint_token_metadata(&ctx.accounts.mint.to_account_info())?;
+    let mut updates: Vec<(Field, String)> = vec![];
+    if let Some(uri) = args.uri.filter(|uri| *uri != token_metadata.uri) {
+        if ctx.accounts.release.reveal_pending {
+            return Err(error!(NinaError::ReleaseRevealPending));
+        }
+        updates.push((Field::Uri, uri));
+    }
+    if let Some(name) = args.name.filter(|name| *name != token_metadata.name) {
+        updates.push((Field::Name, name));
+    }
+    if let Some(symbol) = args.symbol.filter(|symbol| *symbol != token_metadata.symbol) {
+        updates.push((Field::Symbol, symbol));
+    }
+    let additional_metadata: Vec<MetadataField> = args.additional_metadata
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|field| {
+            !token_metadata.additional_metadata
+                .iter()
+                .any(|(key, value)| *key == field.key && *value == field.value)
+        })
+        .collect();
+
+    let seeds = &[
+        ctx.accounts.release.to_account_info().key.as_ref(),
+        &[args.release_signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let metadata_changed = !updates.is_empty() || !additional_metadata.is_empty();
+    for (field, value) in updates {
+        let cpi_accounts = TokenMetadataUpdateField {
+            program_id: ctx.accounts.token_2022_program.to_account_info(),
+            metadata: ctx.accounts.mint.to_account_info(),
+            update_authority: ctx.accounts.release_signer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_2022_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        token_metadata_update_field(cpi_ctx, field, value)?;
+    }
+
+    set_token_metadata_fields(
+        &ctx.accounts.token_2022_program,
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.release,
+        &ctx.accounts.release_signer,
+        &additional_metadata,
+        args.release_signer_bump,
+    )?;
+
+    if metadata_changed {
+        update_mint_balance(
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    if let Some(price) = args.price {
+        ctx.accounts.release.price = price;
+    }
+    if let Some(total_supply) = args.total_supply {
+        ctx.accounts.release.total_supply = total_supply;
+    }
+    if let Some(sale_end) = args.sale_end {
+        ctx.accounts.release.sale_end = if sale_end == 0 { 0 } else { validate_sale_end(Some(sale_end))? };
+    }
+    if let Some(referral_fee) = args.referral_fee {
+        ctx.accounts.release.referral_fee = referral_fee;
+    }
+    if let Some(tip_requires_holder) = args.tip_requires_holder {
+        ctx.accounts.release.tip_requires_holder = tip_requires_holder;
+    }
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 declare_id!("nina2DQvAA8Sa9rxG72swBcNNDYQxdWGojzwDk9yn2q");
 
@@ -6,16 +8,19 @@ pub mod state;
 pub mod instructions;
 pub mod utils;
 pub mod errors;
+pub mod events;
 
 pub use state::*;
 pub use instructions::*;
 pub use utils::*;
 pub use errors::*;
+pub use events::*;
   
 #[program]
 pub mod nina_v2 {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn release_init_v2(
         ctx: Context<ReleaseInitV2>,
         uri: String,
@@ -25,7 +30,21 @@ pub mod nina_v2 {
         price: u64,
         release_signer_bump: u8,
     ) -> Result<()> {
-        instructions::release_init_v2::handler(ctx, uri, name, symbol, total_supply, price, release_signer_bump)
+        instructions::release_init_v2::handler(ctx, uri, name, symbol, total_supply, price, release_signer_bump, ReleaseConfig::default())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn release_init_v2_with_config(
+        ctx: Context<ReleaseInitV2>,
+        uri: String,
+        name: String,
+        symbol: String,
+        total_supply:u64,
+        price: u64,
+        release_signer_bump: u8,
+        config: ReleaseConfig,
+    ) -> Result<()> {
+        instructions::release_init_v2::handler(ctx, uri, name, symbol, total_supply, price, release_signer_bump, config)
     }
 
     pub fn release_purchase<'c: 'info, 'info>(
@@ -57,9 +76,34 @@ pub mod nina_v2 {
             symbol,
             total_supply,
             price,
+            ReleaseConfig::default(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn release_init_and_purchase_with_config<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ReleaseInitAndPurchase<'info>>,
+        release_signer_bump: u8,
+        uri: String,
+        name: String,
+        symbol: String,
+        total_supply: u64,
+        price: u64,
+        config: ReleaseConfig,
+    ) -> Result<()> {
+        instructions::release_init_and_purchase::handler(
+            ctx,
+            release_signer_bump,
+            uri,
+            name,
+            symbol,
+            total_supply,
+            price,
+            config,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn release_update<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, ReleaseUpdate<'info>>,
         uri: String,
@@ -68,8 +112,9 @@ pub mod nina_v2 {
         release_signer_bump: u8,
         price: u64,
         total_supply: u64,  
+        additional_metadata: Vec<MetadataField>,
     ) -> Result<()> {
-        instructions::release_update::handler(ctx, uri, name, symbol, release_signer_bump, price, total_supply)
+        instructions::release_update::handler(ctx, uri, name, symbol, release_signer_bump, price, total_supply, additional_metadata)
     }
 
     pub fn release_close<'c: 'info, 'info>(
@@ -77,4 +122,393 @@ pub mod nina_v2 {
     ) -> Result<()> {
         instructions::release_close::handler(ctx)
     }
+
+    pub fn release_purchase_with_voucher<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ReleasePurchaseWithVoucher<'info>>,
+        price: u64,
+        expiry: i64,
+        nonce: u64,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_purchase_with_voucher::handler(
+            ctx,
+            price,
+            expiry,
+            nonce,
+            release_signer_bump,
+        )
+    }
+
+    pub fn release_airdrop<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ReleaseAirdrop<'info>>,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_airdrop::handler(ctx, release_signer_bump)
+    }
+
+    pub fn release_purchase_gift<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ReleasePurchaseGift<'info>>,
+        amount: u64,
+        release_signer_bump: u8,
+        message: Option<String>,
+    ) -> Result<()> {
+        instructions::release_purchase_gift::handler(
+            ctx,
+            amount,
+            release_signer_bump,
+            message,
+        )
+    }
+
+    pub fn bundle_init<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, BundleInit<'info>>,
+        bundle_id: u64,
+        price: u64,
+        shares: Vec<u64>,
+    ) -> Result<()> {
+        instructions::bundle_init::handler(ctx, bundle_id, price, shares)
+    }
+
+    pub fn bundle_purchase<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, BundlePurchase<'info>>,
+        amount: u64,
+        release_signer_bumps: Vec<u8>,
+    ) -> Result<()> {
+        instructions::bundle_purchase::handler(ctx, amount, release_signer_bumps)
+    }
+
+    pub fn album_init(
+        ctx: Context<AlbumInit>,
+        args: AlbumInitArgs,
+    ) -> Result<()> {
+        instructions::album_init::handler(ctx, args)
+    }
+
+    pub fn album_add_release(
+        ctx: Context<AlbumAddRelease>,
+        album_release_signer_bump: u8,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::album_add_release::handler(ctx, album_release_signer_bump, release_signer_bump)
+    }
+
+    pub fn album_purchase<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AlbumPurchase<'info>>,
+        amount: u64,
+        release_signer_bump: u8,
+        member_release_signer_bumps: Vec<u8>,
+    ) -> Result<()> {
+        instructions::album_purchase::handler(
+            ctx,
+            amount,
+            release_signer_bump,
+            member_release_signer_bumps,
+        )
+    }
+
+    pub fn release_transfer_hook_init(
+        ctx: Context<ReleaseTransferHookInit>,
+    ) -> Result<()> {
+        instructions::release_transfer_hook_init::handler(ctx)
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn release_transfer_hook(
+        ctx: Context<ReleaseTransferHook>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::release_transfer_hook::handler(ctx, amount)
+    }
+
+    pub fn release_resale_approve(
+        ctx: Context<ReleaseResaleApprove>,
+        price: u64,
+    ) -> Result<()> {
+        instructions::release_resale_approve::handler(ctx, price)
+    }
+
+    pub fn listing_create(
+        ctx: Context<ListingCreate>,
+        price: u64,
+    ) -> Result<()> {
+        instructions::listing_create::handler(ctx, price)
+    }
+
+    pub fn listing_cancel(
+        ctx: Context<ListingCancel>,
+    ) -> Result<()> {
+        instructions::listing_cancel::handler(ctx)
+    }
+
+    pub fn listing_buy(
+        ctx: Context<ListingBuy>,
+        price: u64,
+    ) -> Result<()> {
+        instructions::listing_buy::handler(ctx, price)
+    }
+
+    pub fn release_mint_close(
+        ctx: Context<ReleaseMintClose>,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_mint_close::handler(ctx, release_signer_bump)
+    }
+
+    pub fn release_token_revoke(
+        ctx: Context<ReleaseTokenRevoke>,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_token_revoke::handler(ctx, release_signer_bump)
+    }
+
+    pub fn release_metadata_remove_key(
+        ctx: Context<ReleaseMetadataRemoveKey>,
+        key: String,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_metadata_remove_key::handler(ctx, key, release_signer_bump)
+    }
+
+    pub fn release_update_partial(
+        ctx: Context<ReleaseUpdatePartial>,
+        args: ReleaseUpdatePartialArgs,
+    ) -> Result<()> {
+        instructions::release_update_partial::handler(ctx, args)
+    }
+
+    pub fn release_reveal_init(
+        ctx: Context<ReleaseRevealInit>,
+        uri_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::release_reveal_init::handler(ctx, uri_hash)
+    }
+
+    pub fn release_reveal(
+        ctx: Context<ReleaseReveal>,
+        uri: String,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_reveal::handler(ctx, uri, release_signer_bump)
+    }
+
+    pub fn crowdfund_init(
+        ctx: Context<CrowdfundInit>,
+        goal_amount: u64,
+        goal_copies: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::crowdfund_init::handler(ctx, goal_amount, goal_copies, deadline)
+    }
+
+    pub fn crowdfund_purchase(
+        ctx: Context<CrowdfundPurchase>,
+        amount: u64,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::crowdfund_purchase::handler(ctx, amount, release_signer_bump)
+    }
+
+    pub fn crowdfund_finalize(
+        ctx: Context<CrowdfundFinalize>,
+    ) -> Result<()> {
+        instructions::crowdfund_finalize::handler(ctx)
+    }
+
+    pub fn release_refund(
+        ctx: Context<ReleaseRefund>,
+    ) -> Result<()> {
+        instructions::release_refund::handler(ctx)
+    }
+
+    pub fn release_preorder_init(
+        ctx: Context<ReleasePreorderInit>,
+        release_date: i64,
+    ) -> Result<()> {
+        instructions::release_preorder_init::handler(ctx, release_date)
+    }
+
+    pub fn release_preorder(
+        ctx: Context<ReleasePreorder>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::release_preorder::handler(ctx, amount)
+    }
+
+    pub fn release_fulfill_preorder(
+        ctx: Context<ReleaseFulfillPreorder>,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_fulfill_preorder::handler(ctx, release_signer_bump)
+    }
+
+    pub fn release_preorder_cancel(
+        ctx: Context<ReleasePreorderCancel>,
+    ) -> Result<()> {
+        instructions::release_preorder_cancel::handler(ctx)
+    }
+
+    pub fn release_preorder_reclaim(
+        ctx: Context<ReleasePreorderReclaim>,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_preorder_reclaim::handler(ctx, release_signer_bump)
+    }
+
+    pub fn hub_init(
+        ctx: Context<HubInit>,
+        hub_id: u64,
+        fee: u64,
+        uri: String,
+    ) -> Result<()> {
+        instructions::hub_init::handler(ctx, hub_id, fee, uri)
+    }
+
+    pub fn hub_add_release(
+        ctx: Context<HubAddRelease>,
+    ) -> Result<()> {
+        instructions::hub_add_release::handler(ctx)
+    }
+
+    pub fn hub_remove_release(
+        ctx: Context<HubRemoveRelease>,
+    ) -> Result<()> {
+        instructions::hub_remove_release::handler(ctx)
+    }
+
+    pub fn hub_release_purchase(
+        ctx: Context<HubReleasePurchase>,
+        amount: u64,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::hub_release_purchase::handler(ctx, amount, release_signer_bump)
+    }
+
+    pub fn profile_init(
+        ctx: Context<ProfileInit>,
+        handle: String,
+        uri: String,
+        payout_token_account: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::profile_init::handler(ctx, handle, uri, payout_token_account)
+    }
+
+    pub fn profile_update(
+        ctx: Context<ProfileUpdate>,
+        handle: String,
+        uri: String,
+        payout_token_account: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::profile_update::handler(ctx, handle, uri, payout_token_account)
+    }
+
+    pub fn profile_verify(
+        ctx: Context<ProfileVerify>,
+        verified: bool,
+    ) -> Result<()> {
+        instructions::profile_verify::handler(ctx, verified)
+    }
+
+    pub fn label_init(
+        ctx: Context<LabelInit>,
+        label_id: u64,
+    ) -> Result<()> {
+        instructions::label_init::handler(ctx, label_id)
+    }
+
+    pub fn label_set_manager(
+        ctx: Context<LabelSetManager>,
+        manager: Pubkey,
+        roles: u8,
+    ) -> Result<()> {
+        instructions::label_set_manager::handler(ctx, manager, roles)
+    }
+
+    pub fn label_withdraw(
+        ctx: Context<LabelWithdraw>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::label_withdraw::handler(ctx, amount)
+    }
+
+    pub fn multisig_init(
+        ctx: Context<MultisigInit>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::multisig_init::handler(ctx, members, threshold)
+    }
+
+    pub fn multisig_propose(
+        ctx: Context<MultisigPropose>,
+        action: MultisigAction,
+    ) -> Result<()> {
+        instructions::multisig_propose::handler(ctx, action)
+    }
+
+    pub fn multisig_approve(
+        ctx: Context<MultisigApprove>,
+    ) -> Result<()> {
+        instructions::multisig_approve::handler(ctx)
+    }
+
+    pub fn multisig_execute(
+        ctx: Context<MultisigExecute>,
+    ) -> Result<()> {
+        instructions::multisig_execute::handler(ctx)
+    }
+
+    pub fn release_tip(
+        ctx: Context<ReleaseTip>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::release_tip::handler(ctx, amount)
+    }
+
+    pub fn release_claim_configure(
+        ctx: Context<ReleaseClaimConfigure>,
+        wallet_limit: u64,
+        voucher_required: bool,
+    ) -> Result<()> {
+        instructions::release_claim_configure::handler(ctx, wallet_limit, voucher_required)
+    }
+
+    pub fn release_claim(
+        ctx: Context<ReleaseClaim>,
+        release_signer_bump: u8,
+        voucher_expiry: i64,
+        voucher_nonce: u64,
+    ) -> Result<()> {
+        instructions::release_claim::handler(ctx, release_signer_bump, voucher_expiry, voucher_nonce)
+    }
+
+    pub fn release_tier_init(
+        ctx: Context<ReleaseTierInit>,
+        args: ReleaseTierInitArgs,
+    ) -> Result<()> {
+        instructions::release_tier_init::handler(ctx, args)
+    }
+
+    pub fn release_purchase_tier(
+        ctx: Context<ReleasePurchaseTier>,
+        tier_index: u8,
+        amount: u64,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_purchase_tier::handler(ctx, tier_index, amount, release_signer_bump)
+    }
+
+    pub fn release_numbered_init(
+        ctx: Context<ReleaseNumberedInit>,
+        args: ReleaseNumberedInitArgs,
+    ) -> Result<()> {
+        instructions::release_numbered_init::handler(ctx, args)
+    }
+
+    pub fn release_purchase_numbered(
+        ctx: Context<ReleasePurchaseNumbered>,
+        amount: u64,
+        release_signer_bump: u8,
+    ) -> Result<()> {
+        instructions::release_purchase_numbered::handler(ctx, amount, release_signer_bump)
+    }
 }
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+pub const BUNDLE_MAX_RELEASES: usize = 10;
+
+#[account]
+pub struct Bundle { // 492 bytes
+  pub authority: Pubkey, // 32 bytes
+  pub payment_mint: Pubkey, // 32 bytes
+  pub bundle_id: u64, // 8 bytes
+  pub price: u64, // 8 bytes
+  pub releases: Vec<BundleRelease>, // 4 + 40 * BUNDLE_MAX_RELEASES bytes
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BundleRelease {
+  pub release: Pubkey, // 32 bytes
+  pub share: u64, // 8 bytes, out of BASIS_POINTS
+}
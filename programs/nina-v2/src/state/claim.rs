@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ClaimConfig { // 50 bytes
+  pub release: Pubkey, // 32 bytes
+  pub wallet_limit: u64, // 8 bytes, 0 for no limit
+  pub voucher_required: bool, // 1 byte
+  pub bump: u8, // 1 byte
+}
+
+#[account]
+pub struct ClaimRecord { // 80 bytes
+  pub release: Pubkey, // 32 bytes
+  pub claimant: Pubkey, // 32 bytes
+  pub count: u64, // 8 bytes
+}
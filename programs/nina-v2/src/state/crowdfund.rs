@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Crowdfund { // 82 bytes
+  pub release: Pubkey, // 32 bytes
+  pub goal_amount: u64, // 8 bytes, 0 for no amount goal
+  pub goal_copies: u64, // 8 bytes, 0 for no copies goal
+  pub deadline: i64, // 8 bytes, unix timestamp
+  pub raised: u64, // 8 bytes
+  pub copies: u64, // 8 bytes
+  pub finalized: bool, // 1 byte
+  pub bump: u8, // 1 byte
+}
+
+impl Crowdfund {
+  pub fn goal_met(&self) -> bool {
+    self.raised >= self.goal_amount && self.copies >= self.goal_copies
+  }
+}
+
+#[account]
+pub struct CrowdfundContribution { // 88 bytes
+  pub crowdfund: Pubkey, // 32 bytes
+  pub buyer: Pubkey, // 32 bytes
+  pub amount: u64, // 8 bytes
+  pub copies: u64, // 8 bytes
+}
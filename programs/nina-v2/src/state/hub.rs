@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Hub { // 260 bytes
+  pub authority: Pubkey, // 32 bytes
+  pub hub_id: u64, // 8 bytes
+  pub fee: u64, // 8 bytes, out of BASIS_POINTS
+  pub uri: String, // 4 + RELEASE_URI_MAX_LENGTH bytes
+}
+
+#[account]
+pub struct HubRelease { // 72 bytes
+  pub hub: Pubkey, // 32 bytes
+  pub release: Pubkey, // 32 bytes
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+pub const LABEL_MAX_MANAGERS: usize = 10;
+
+// manager role bit flags
+pub const LABEL_ROLE_UPDATE_METADATA: u8 = 1 << 0;
+pub const LABEL_ROLE_PRICE: u8 = 1 << 1; // price and the other sale terms
+pub const LABEL_ROLE_CLOSE: u8 = 1 << 2;
+pub const LABEL_ROLE_AIRDROP: u8 = 1 << 3;
+
+#[account]
+pub struct Label { // 383 bytes
+  pub authority: Pubkey, // 32 bytes, holds every role
+  pub label_id: u64, // 8 bytes
+  pub managers: Vec<LabelManager>, // 4 + 33 * LABEL_MAX_MANAGERS bytes
+  pub bump: u8, // 1 byte
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LabelManager {
+  pub manager: Pubkey, // 32 bytes
+  pub roles: u8, // 1 byte, LABEL_ROLE_* flags
+}
+
+impl Label {
+  pub fn has_roles(&self, manager: Pubkey, roles: u8) -> bool {
+    manager == self.authority
+      || self.managers
+        .iter()
+        .any(|label_manager| label_manager.manager == manager && label_manager.roles & roles == roles)
+  }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Listing { // 113 bytes
+  pub seller: Pubkey, // 32 bytes
+  pub release: Pubkey, // 32 bytes
+  pub mint: Pubkey, // 32 bytes
+  pub price: u64, // 8 bytes
+  pub bump: u8, // 1 byte
+}
@@ -1,3 +1,29 @@
 pub mod release;
+pub mod voucher;
+pub mod bundle;
+pub mod transfer_approval;
+pub mod listing;
+pub mod reveal;
+pub mod crowdfund;
+pub mod preorder;
+pub mod hub;
+pub mod profile;
+pub mod label;
+pub mod multisig;
+pub mod claim;
+pub mod tier;
 
-pub use release::*;
\ No newline at end of file
+pub use release::*;
+pub use voucher::*;
+pub use bundle::*;
+pub use transfer_approval::*;
+pub use listing::*;
+pub use reveal::*;
+pub use crowdfund::*;
+pub use preorder::*;
+pub use hub::*;
+pub use profile::*;
+pub use label::*;
+pub use multisig::*;
+pub use claim::*;
+pub use tier::*;
\ No newline at end of file
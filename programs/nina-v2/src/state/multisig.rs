@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+pub const MULTISIG_MAX_MEMBERS: usize = 10;
+
+#[account]
+pub struct Multisig { // 374 bytes
+  pub release: Pubkey, // 32 bytes
+  pub members: Vec<Pubkey>, // 4 + 32 * MULTISIG_MAX_MEMBERS bytes
+  pub threshold: u8, // 1 byte
+  pub proposal_count: u64, // 8 bytes
+  pub bump: u8, // 1 byte
+}
+
+#[account]
+pub struct MultisigProposal { // 84 bytes
+  pub multisig: Pubkey, // 32 bytes
+  pub proposal_id: u64, // 8 bytes
+  pub action: MultisigAction, // 33 bytes
+  pub approvals: u16, // 2 bytes, bit per member index
+  pub executed: bool, // 1 byte
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum MultisigAction {
+  Update {
+    price: Option<u64>,
+    total_supply: Option<u64>,
+  },
+  Payout {
+    royalty_token_account: Pubkey,
+  },
+  Close,
+  // hands the release back to a single authority for everything else
+  SetAuthority {
+    authority: Pubkey,
+  },
+}
+
+impl Multisig {
+  pub fn member_index(&self, member: Pubkey) -> Option<usize> {
+    self.members.iter().position(|multisig_member| *multisig_member == member)
+  }
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PreorderReceipt { // 88 bytes
+  pub release: Pubkey, // 32 bytes
+  pub buyer: Pubkey, // 32 bytes
+  pub amount: u64, // 8 bytes, held in the release_signer escrow
+  pub copies: u64, // 8 bytes
+}
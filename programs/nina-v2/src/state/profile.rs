@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+pub const PROFILE_HANDLE_MAX_LENGTH: usize = 32;
+
+#[account]
+pub struct Profile { // 314 bytes
+  pub authority: Pubkey, // 32 bytes
+  pub handle: String, // 4 + PROFILE_HANDLE_MAX_LENGTH bytes
+  pub uri: String, // 4 + RELEASE_URI_MAX_LENGTH bytes
+  pub verified: bool, // 1 byte, only set by the identity service
+  pub payout_token_account: Option<Pubkey>, // 33 bytes, where the profile prefers to be paid
+}
+
+#[account]
+pub struct ReleaseProfile { // 72 bytes
+  pub release: Pubkey, // 32 bytes
+  pub profile: Pubkey, // 32 bytes
+}
@@ -9,4 +9,15 @@ pub struct ReleaseV2 { // 240 bytes
   pub payment_mint: Pubkey, // 32 bytes
   pub total_supply: u64, // 8 bytes
   pub price: u64, // 8 bytes
+  pub resale_royalty: u64, // 8 bytes, out of BASIS_POINTS
+  pub open_edition: bool, // 1 byte
+  pub sale_end: i64, // 8 bytes, unix timestamp, 0 for no end
+  pub crowdfunding: bool, // 1 byte, purchases go through crowdfund_purchase until finalized
+  pub release_date: i64, // 8 bytes, unix timestamp, 0 for no pre-order
+  pub preorders_outstanding: u64, // 8 bytes, pre-ordered copies not yet fulfilled or reclaimed
+  pub preorder_cancelled: bool, // 1 byte
+  pub referral_fee: u64, // 8 bytes, out of BASIS_POINTS, paid to a referrer on purchase
+  pub tip_requires_holder: bool, // 1 byte, only holders of the release can tip
+  pub numbered: bool, // 1 byte, each copy is its own mint bought through release_purchase_numbered
+  pub reveal_pending: bool, // 1 byte, the uri is committed and can only change through release_reveal
 }
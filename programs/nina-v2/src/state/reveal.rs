@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct RevealCommitment { // 72 bytes
+  pub release: Pubkey, // 32 bytes
+  pub uri_hash: [u8; 32], // 32 bytes, sha256 of the final uri
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ReleaseTier { // 89 bytes
+  pub release: Pubkey, // 32 bytes
+  pub mint: Pubkey, // 32 bytes
+  pub tier_index: u8, // 1 byte
+  pub price: u64, // 8 bytes
+  pub total_supply: u64, // 8 bytes
+}
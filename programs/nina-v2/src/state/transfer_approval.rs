@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TransferApproval { // 112 bytes
+  pub mint: Pubkey, // 32 bytes
+  pub source: Pubkey, // 32 bytes
+  pub destination: Pubkey, // 32 bytes
+  pub amount: u64, // 8 bytes
+}
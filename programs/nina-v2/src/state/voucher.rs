@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct VoucherRedemption { // 88 bytes
+  pub release: Pubkey, // 32 bytes
+  pub buyer: Pubkey, // 32 bytes
+  pub nonce: u64, // 8 bytes
+  pub price: u64, // 8 bytes
+}
@@ -2,23 +2,37 @@ use anchor_lang::{
   prelude::*,
   solana_program::{
       account_info::AccountInfo,
+      ed25519_program,
       program::invoke,
       pubkey::Pubkey,
       rent::Rent,
       system_instruction::transfer,
-      sysvar::Sysvar,
+      sysvar::{
+          instructions::{load_current_index_checked, load_instruction_at_checked},
+          Sysvar,
+      },
   },
   Lamports,
 };
 use anchor_spl::token_interface::spl_token_2022::{
-  extension::{BaseStateWithExtensions, Extension, StateWithExtensions},
-  state::Mint,
+  extension::{
+      transfer_hook::TransferHookAccount,
+      BaseStateWithExtensions,
+      Extension,
+      ExtensionType,
+      StateWithExtensions,
+  },
+  state::{Account as TokenAccountState, Mint},
 };
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
 use solana_zk_token_sdk::zk_token_proof_instruction::Pod;
 use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
 use spl_type_length_value::variable_len_pack::VariableLenPack;
 use std::str::FromStr;
 
+use crate::state::{Label, ReleaseV2};
+use crate::errors::NinaError;
+
 pub const APPROVE_ACCOUNT_SEED: &[u8] = b"approve-account";
 pub const META_LIST_ACCOUNT_SEED: &[u8] = b"extra-account-metas";
 
@@ -44,6 +58,28 @@ pub fn get_mint_extension_data<T: Extension + Pod>(account: &mut AccountInfo) ->
   Ok(extension_data)
 }
 
+pub fn get_mint_extension_types(account: &AccountInfo) -> Result<Vec<ExtensionType>> {
+  let mint_data = account.data.borrow();
+  let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+  Ok(mint_with_extension.get_extension_types()?)
+}
+
+pub fn get_mint_token_metadata(account: &AccountInfo) -> Result<TokenMetadata> {
+  let mint_data = account.data.borrow();
+  let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+  let token_metadata = mint_with_extension.get_variable_len_extension::<TokenMetadata>()?;
+  Ok(token_metadata)
+}
+
+pub fn check_token_account_is_transferring(account: &AccountInfo) -> Result<()> {
+  let account_data = account.data.borrow();
+  let account_with_extension = StateWithExtensions::<TokenAccountState>::unpack(&account_data)?;
+  let transfer_hook_account = account_with_extension.get_extension::<TransferHookAccount>()?;
+  if !bool::from(transfer_hook_account.transferring) {
+      return Err(error!(NinaError::ReleaseTransferHookNotTransferring));
+  }
+  Ok(())
+}
 
 pub fn get_meta_list(approve_account: Option<Pubkey>) -> Vec<ExtraAccountMeta> {
   if let Some(approve_account) = approve_account {
@@ -62,6 +98,47 @@ pub fn get_meta_list_size(approve_account: Option<Pubkey>) -> usize {
   ExtraAccountMetaList::size_of(get_meta_list(approve_account).len()).unwrap()
 }
 
+// Reads the ed25519 program instruction placed directly before the current one and
+// returns the signer and message it verified. The runtime has already checked the
+// signature, so callers only need to check who signed and what was signed.
+pub fn load_ed25519_signed_message(instructions_sysvar: &AccountInfo) -> Result<(Pubkey, Vec<u8>)> {
+  let current_index = load_current_index_checked(instructions_sysvar)?;
+  if current_index == 0 {
+      return Err(error!(NinaError::VoucherSignatureMissing));
+  }
+
+  let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+  if ed25519_ix.program_id != ed25519_program::ID || !ed25519_ix.accounts.is_empty() {
+      return Err(error!(NinaError::VoucherSignatureMissing));
+  }
+
+  // [num_signatures: u8, padding: u8, offsets: 7 x u16]
+  let data = &ed25519_ix.data;
+  if data.len() < 16 || data[0] != 1 {
+      return Err(error!(NinaError::VoucherSignatureInvalid));
+  }
+  let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+
+  // all offsets must point into the ed25519 instruction itself
+  if read_u16(4) != u16::MAX || read_u16(8) != u16::MAX || read_u16(14) != u16::MAX {
+      return Err(error!(NinaError::VoucherSignatureInvalid));
+  }
+
+  let public_key_offset = read_u16(6) as usize;
+  let message_offset = read_u16(10) as usize;
+  let message_size = read_u16(12) as usize;
+  let public_key = data
+      .get(public_key_offset..public_key_offset + 32)
+      .ok_or(NinaError::VoucherSignatureInvalid)?;
+  let message = data
+      .get(message_offset..message_offset + message_size)
+      .ok_or(NinaError::VoucherSignatureInvalid)?;
+
+  let signer = Pubkey::try_from(public_key).map_err(|_| NinaError::VoucherSignatureInvalid)?;
+
+  Ok((signer, message.to_vec()))
+}
+
 pub fn file_service_account_key() -> Pubkey {
   Pubkey::from_str("HQUtBQzt8d5ZtxAwfbPLE6TpBq68wJQ7ZaSjQDEn4Hz6").unwrap()
 }
@@ -70,3 +147,19 @@ pub fn id_account_key() -> Pubkey {
   Pubkey::from_str("BnhxwsrY5aaeMehsTRoJzX2X4w5sKMhMfBs2MCKUqMC").unwrap()
 }
 
+// Signer has to be the release authority or, when a label is the release
+// authority, one of its managers with the roles needed
+pub fn validate_release_authority(
+  release: &ReleaseV2,
+  authority: Pubkey,
+  label: &Option<Box<Account<Label>>>,
+  roles: u8,
+) -> Result<()> {
+  if authority == release.authority {
+    return Ok(());
+  }
+  match label {
+    Some(label) if label.key() == release.authority && label.has_roles(authority, roles) => Ok(()),
+    _ => Err(error!(NinaError::ReleaseUnauthorized)),
+  }
+}
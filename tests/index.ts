@@ -13,15 +13,20 @@ import {
   getAssociatedTokenAddress,
   getAccount,
   getTokenMetadata,
+  createTransferCheckedWithTransferHookInstruction,
+  createTransferCheckedInstruction,
 } from "@solana/spl-token";
 import {
   ComputeBudgetProgram,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 
 import {
   buildSignAndSendTransaction,
 } from "./helpers/index";
 import { expect } from "chai";
+import { createHash } from "crypto";
 
 const TOKEN_2022_PROGRAM_ID = new anchor.web3.PublicKey(
   "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
@@ -715,6 +720,7 @@ describe("nina-v2", () => {
         releaseSigner,
         release,
         mint: mint4.publicKey,
+        label: null,
       })
       .instruction();
 
@@ -766,6 +772,10 @@ describe("nina-v2", () => {
         releaseSignerBump,
         new anchor.BN(RELEASE_PRICE * 5),
         new anchor.BN(1000),
+        [
+          { key: "genre", value: "ambient" },
+          { key: "isrc", value: "USRC17607839" },
+        ],
       )
       .accountsStrict({
         payer: artist.publicKey,
@@ -775,6 +785,8 @@ describe("nina-v2", () => {
         mint: mint3.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
         token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+        manager: null,
       })
       .instruction();
     const txid = await buildSignAndSendTransaction(
@@ -803,11 +815,3584 @@ describe("nina-v2", () => {
     expect(metadata.uri).to.equal(`https://arweave.net/ZIdtfNs7XKWlIz3_n1CnfYhKNHlWgnHyM7SfNXrZ1aQ`);
     expect(metadata.name).to.equal("Nina Test2");
     expect(metadata.symbol).to.equal("NINA2");
+    expect(metadata.additionalMetadata).to.deep.equal([
+      ["genre", "ambient"],
+      ["isrc", "USRC17607839"],
+    ]);
 
     const releaseData = await program.account.releaseV2.fetch(release);
     expect(Number(releaseData.price)).to.equal(RELEASE_PRICE * 5);
     expect(Number(releaseData.totalSupply)).to.equal(1000);
   });
+
+  it("Remove a Metadata field", async () => {
+    const [release] = await anchor.web3.PublicKey.findProgramAddress(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        mint3.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const ix = await program.methods
+      .releaseMetadataRemoveKey("isrc", releaseSignerBump)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        releaseSigner,
+        release,
+        mint: mint3.publicKey,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+    const txid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, ix],
+      artist,
+      lightConnection,
+      [],
+    );
+    const latestBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: latestBlockHash.blockhash,
+        lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const metadata = await getTokenMetadata(lightConnection, mint3.publicKey, 'confirmed');
+    expect(metadata.additionalMetadata).to.deep.equal([["genre", "ambient"]]);
+  });
+
+  it("Partially update a Release", async () => {
+    const [release] = await anchor.web3.PublicKey.findProgramAddress(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        mint3.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const ix = await program.methods
+      .releaseUpdatePartial({
+        uri: null,
+        name: "Nina Test3",
+        symbol: null,
+        price: null,
+        totalSupply: new anchor.BN(500),
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: null,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        releaseSigner,
+        release,
+        mint: mint3.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+    const txid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, ix],
+      artist,
+      lightConnection,
+      [],
+    );
+    const latestBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: latestBlockHash.blockhash,
+        lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const metadata = await getTokenMetadata(lightConnection, mint3.publicKey, 'confirmed');
+    expect(metadata.uri).to.equal(`https://arweave.net/ZIdtfNs7XKWlIz3_n1CnfYhKNHlWgnHyM7SfNXrZ1aQ`);
+    expect(metadata.name).to.equal("Nina Test3");
+    expect(metadata.symbol).to.equal("NINA2");
+    expect(metadata.additionalMetadata).to.deep.equal([["genre", "ambient"]]);
+
+    const releaseData = await program.account.releaseV2.fetch(release);
+    expect(Number(releaseData.price)).to.equal(RELEASE_PRICE * 5);
+    expect(Number(releaseData.totalSupply)).to.equal(500);
+  });
+
+  it("Reject invalid Release updates", async () => {
+    const [release] = await anchor.web3.PublicKey.findProgramAddress(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        mint3.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const invalidUpdates = [
+      { uri: "http://example.com/release.json", name: null, symbol: null, totalSupply: null },
+      { uri: null, name: "N".repeat(33), symbol: null, totalSupply: null },
+      { uri: null, name: null, symbol: "NINANINANINA", totalSupply: null },
+      { uri: null, name: null, symbol: null, totalSupply: new anchor.BN(0) },
+    ];
+    for (const update of invalidUpdates) {
+      const ix = await program.methods
+        .releaseUpdatePartial({
+          ...update,
+          price: null,
+          additionalMetadata: null,
+          saleEnd: null,
+          referralFee: null,
+          tipRequiresHolder: null,
+          releaseSignerBump,
+        })
+        .accountsStrict({
+          payer: artist.publicKey,
+          authority: artist.publicKey,
+          releaseSigner,
+          release,
+          mint: mint3.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          label: null,
+        })
+        .instruction();
+      const txid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ix],
+        artist,
+        lightConnection,
+        [],
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      const result = await lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: txid,
+        },
+        'finalized',
+      );
+      expect(result.value.err).to.not.be.null;
+    }
+
+    const metadata = await getTokenMetadata(lightConnection, mint3.publicKey, 'confirmed');
+    expect(metadata.name).to.equal("Nina Test3");
+    const releaseData = await program.account.releaseV2.fetch(release);
+    expect(Number(releaseData.totalSupply)).to.equal(500);
+  });
+
+  it("Purchase a Release with a voucher", async () => {
+    const purchaserTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+
+    const [release] = await anchor.web3.PublicKey.findProgramAddress(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        mint2.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+
+    const voucherPrice = new anchor.BN(RELEASE_PRICE / 2);
+    const expiry = new anchor.BN(Math.floor(Date.now() / 1000) + 60 * 60);
+    const nonce = new anchor.BN(1);
+    const [voucherRedemption] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-voucher")),
+        release.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const message = Buffer.concat([
+      release.toBuffer(),
+      purchaser.publicKey.toBuffer(),
+      voucherPrice.toArrayLike(Buffer, "le", 8),
+      expiry.toTwos(64).toArrayLike(Buffer, "le", 8),
+      nonce.toArrayLike(Buffer, "le", 8),
+    ]);
+    const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: artist.secretKey,
+      message,
+    });
+
+    const ix = await program.methods
+      .releasePurchaseWithVoucher(
+        voucherPrice,
+        expiry,
+        nonce,
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        voucherRedemption,
+        mint: mint2.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        receiverReleaseTokenAccount: associatedAddress({
+          mint: mint2.publicKey,
+          owner: purchaser.publicKey,
+          tokenProgramId: TOKEN_2022_PROGRAM_ID,
+        }),
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+    const txid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, ed25519Ix, ix],
+      purchaser,
+      lightConnection,
+      []
+    );
+    if (txid) {
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      await lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: txid,
+        },
+        'finalized',
+      );
+    }
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    const purchaserTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    expect(Number(purchaserTokenBalance.value.amount)).to.equal(Number(purchaserTokenBalanceBefore.value.amount) - (RELEASE_PRICE / 2));
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + (RELEASE_PRICE / 2));
+
+    const voucherRedemptionData = await program.account.voucherRedemption.fetch(voucherRedemption);
+    expect(voucherRedemptionData.buyer.toBase58()).to.equal(purchaser.publicKey.toBase58());
+    expect(Number(voucherRedemptionData.nonce)).to.equal(1);
+  });
+
+  it("Airdrop a Release to multiple recipients", async () => {
+    const [release] = await anchor.web3.PublicKey.findProgramAddress(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        mint2.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const supplyBefore = await lightConnection.getTokenSupply(mint2.publicKey, 'confirmed');
+
+    const recipients = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+    const remainingAccounts = recipients.flatMap((recipient) => [
+      { pubkey: recipient, isSigner: false, isWritable: false },
+      {
+        pubkey: associatedAddress({
+          mint: mint2.publicKey,
+          owner: recipient,
+          tokenProgramId: TOKEN_2022_PROGRAM_ID,
+        }),
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+
+    const ix = await program.methods
+      .releaseAirdrop(releaseSignerBump)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        releaseSigner,
+        mint: mint2.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const txid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, ix],
+      artist,
+      lightConnection,
+      []
+    );
+    if (txid) {
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      await lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: txid,
+        },
+        'finalized',
+      );
+    }
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    for (const recipient of recipients) {
+      const recipientBalance = await lightConnection.getTokenAccountBalance(
+        associatedAddress({
+          mint: mint2.publicKey,
+          owner: recipient,
+          tokenProgramId: TOKEN_2022_PROGRAM_ID,
+        }),
+        'confirmed'
+      );
+      expect(Number(recipientBalance.value.amount)).to.equal(1);
+    }
+    const supplyAfter = await lightConnection.getTokenSupply(mint2.publicKey, 'confirmed');
+    expect(Number(supplyAfter.value.amount)).to.equal(Number(supplyBefore.value.amount) + recipients.length);
+  });
+
+  it("Gift a Release to a receiver who does not sign", async () => {
+    const purchaserTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const [release] = await anchor.web3.PublicKey.findProgramAddress(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        mint2.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const giftReceiver = Keypair.generate().publicKey;
+    const giftReceiverReleaseTokenAccount = associatedAddress({
+      mint: mint2.publicKey,
+      owner: giftReceiver,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+
+    const ix = await program.methods
+      .releasePurchaseGift(
+        new anchor.BN(RELEASE_PRICE * 20),
+        releaseSignerBump,
+        "happy birthday",
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        buyer: purchaser.publicKey,
+        receiver: giftReceiver,
+        release,
+        releaseSigner,
+        mint: mint2.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        receiverReleaseTokenAccount: giftReceiverReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+    const txid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, ix],
+      purchaser,
+      lightConnection,
+      []
+    );
+    if (txid) {
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      await lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: txid,
+        },
+        'finalized',
+      );
+    }
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    const purchaserTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    expect(Number(purchaserTokenBalance.value.amount)).to.equal(Number(purchaserTokenBalanceBefore.value.amount) - (RELEASE_PRICE * 20));
+    const giftReceiverBalance = await lightConnection.getTokenAccountBalance(giftReceiverReleaseTokenAccount, 'confirmed');
+    expect(Number(giftReceiverBalance.value.amount)).to.equal(1);
+  });
+
+  it("Create a Bundle and purchase it", async () => {
+    const bundleId = new anchor.BN(1);
+    const [bundle] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-bundle")),
+        artist.publicKey.toBuffer(),
+        bundleId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const bundleMints = [mint.publicKey, mint2.publicKey];
+    const bundleReleases = bundleMints.map((bundleMint) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+          bundleMint.toBuffer(),
+        ],
+        program.programId
+      )[0]
+    );
+
+    const initIx = await program.methods
+      .bundleInit(
+        bundleId,
+        new anchor.BN(RELEASE_PRICE * 15),
+        [new anchor.BN(500000), new anchor.BN(500000)],
+      )
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        bundle,
+        paymentMint: paymentMint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .remainingAccounts(
+        bundleReleases.map((release) => ({ pubkey: release, isSigner: false, isWritable: false }))
+      )
+      .instruction();
+
+    const initTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, initIx],
+      artist,
+      lightConnection,
+      []
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: initTxid,
+      },
+      'finalized',
+    );
+
+    const bundleData = await program.account.bundle.fetch(bundle);
+    expect(bundleData.releases.length).to.equal(2);
+    expect(Number(bundleData.price)).to.equal(RELEASE_PRICE * 15);
+
+    const purchaserTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+
+    const releaseSignerBumps = [];
+    const remainingAccounts = [];
+    bundleReleases.forEach((release, index) => {
+      const [releaseSigner, releaseSignerBump] =
+        anchor.web3.PublicKey.findProgramAddressSync(
+          [release.toBuffer()],
+          program.programId
+        );
+      releaseSignerBumps.push(releaseSignerBump);
+      remainingAccounts.push(
+        { pubkey: release, isSigner: false, isWritable: false },
+        { pubkey: releaseSigner, isSigner: false, isWritable: false },
+        { pubkey: bundleMints[index], isSigner: false, isWritable: true },
+        { pubkey: royaltyTokenAccount, isSigner: false, isWritable: true },
+        {
+          pubkey: associatedAddress({
+            mint: bundleMints[index],
+            owner: purchaser.publicKey,
+            tokenProgramId: TOKEN_2022_PROGRAM_ID,
+          }),
+          isSigner: false,
+          isWritable: true,
+        },
+      );
+    });
+
+    const purchaseIx = await program.methods
+      .bundlePurchase(
+        new anchor.BN(RELEASE_PRICE * 15),
+        Buffer.from(releaseSignerBumps),
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        bundle,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const purchaseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, purchaseIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const purchaseBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: purchaseBlockHash.blockhash,
+        lastValidBlockHeight: purchaseBlockHash.lastValidBlockHeight,
+        signature: purchaseTxid,
+      },
+      'finalized',
+    );
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    const purchaserTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    expect(Number(purchaserTokenBalance.value.amount)).to.equal(Number(purchaserTokenBalanceBefore.value.amount) - (RELEASE_PRICE * 15));
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + (RELEASE_PRICE * 15));
+  });
+
+  it("Create an Album, add a track and purchase the Album", async () => {
+    const albumMint = Keypair.generate();
+    const trackMint = Keypair.generate();
+
+    const { txid: trackTxid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      trackMint,
+      undefined,
+    );
+    const trackBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: trackBlockHash.blockhash,
+        lastValidBlockHeight: trackBlockHash.lastValidBlockHeight,
+        signature: trackTxid,
+      },
+      'finalized',
+    );
+
+    const [albumRelease] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        albumMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [albumReleaseSigner, albumReleaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [albumRelease.toBuffer()],
+        program.programId
+      );
+    const [trackRelease] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        trackMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [trackReleaseSigner, trackReleaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [trackRelease.toBuffer()],
+        program.programId
+      );
+
+    const albumInitIx = await program.methods
+      .albumInit({
+        uri: `https://arweave.net/rb9wx261pn2nCbiHtoqR2vQtZ3MRQ3qcyZeSSCE0Rm4`,
+        name: "Nina Album",
+        symbol: "NINA",
+        totalSupply: new anchor.BN(100),
+        price: new anchor.BN(RELEASE_PRICE * 3),
+        releaseSignerBump: albumReleaseSignerBump,
+        maxSize: new anchor.BN(10),
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release: albumRelease,
+        mint: albumMint.publicKey,
+        releaseSigner: albumReleaseSigner,
+        paymentMint: paymentMint.publicKey,
+        royaltyTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+    const addReleaseIx = await program.methods
+      .albumAddRelease(albumReleaseSignerBump, trackReleaseSignerBump)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        albumRelease,
+        albumReleaseSigner,
+        albumMint: albumMint.publicKey,
+        release: trackRelease,
+        releaseSigner: trackReleaseSigner,
+        mint: trackMint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+
+    const albumTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, albumInitIx, addReleaseIx],
+      artist,
+      lightConnection,
+      [],
+      [albumMint]
+    );
+    const albumBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: albumBlockHash.blockhash,
+        lastValidBlockHeight: albumBlockHash.lastValidBlockHeight,
+        signature: albumTxid,
+      },
+      'finalized',
+    );
+
+    const purchaserTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const trackReceiverReleaseTokenAccount = associatedAddress({
+      mint: trackMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+
+    const purchaseIx = await program.methods
+      .albumPurchase(
+        new anchor.BN(RELEASE_PRICE * 3),
+        albumReleaseSignerBump,
+        Buffer.from([trackReleaseSignerBump]),
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release: albumRelease,
+        releaseSigner: albumReleaseSigner,
+        mint: albumMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        receiverReleaseTokenAccount: associatedAddress({
+          mint: albumMint.publicKey,
+          owner: purchaser.publicKey,
+          tokenProgramId: TOKEN_2022_PROGRAM_ID,
+        }),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        { pubkey: trackRelease, isSigner: false, isWritable: false },
+        { pubkey: trackReleaseSigner, isSigner: false, isWritable: false },
+        { pubkey: trackMint.publicKey, isSigner: false, isWritable: true },
+        { pubkey: trackReceiverReleaseTokenAccount, isSigner: false, isWritable: true },
+      ])
+      .instruction();
+
+    const purchaseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, purchaseIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const purchaseBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: purchaseBlockHash.blockhash,
+        lastValidBlockHeight: purchaseBlockHash.lastValidBlockHeight,
+        signature: purchaseTxid,
+      },
+      'finalized',
+    );
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    const purchaserTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    expect(Number(purchaserTokenBalance.value.amount)).to.equal(Number(purchaserTokenBalanceBefore.value.amount) - (RELEASE_PRICE * 3));
+    const trackBalance = await lightConnection.getTokenAccountBalance(trackReceiverReleaseTokenAccount, 'confirmed');
+    expect(Number(trackBalance.value.amount)).to.equal(1);
+  });
+
+  it("Resell a transfer hook Release with a royalty", async () => {
+    const hookMint = Keypair.generate();
+    const resaleRoyalty = 100000;
+    const resalePrice = RELEASE_PRICE * 2;
+
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      hookMint,
+      undefined,
+      RELEASE_PRICE,
+      { transferHook: true, resaleRoyalty: new anchor.BN(resaleRoyalty), nonTransferable: false, permanentDelegate: false, mintCloseAuthority: false, additionalMetadata: [], openEdition: false, saleEnd: null, referralFee: new anchor.BN(0), tipRequiresHolder: false },
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const [transferApproval] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("approve-account"), hookMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [extraAccountMetaList] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), hookMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const sellerReleaseTokenAccount = associatedAddress({
+      mint: hookMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const buyerReleaseTokenAccount = associatedAddress({
+      mint: hookMint.publicKey,
+      owner: payer.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+
+    const hookInitIx = await program.methods
+      .releaseTransferHookInit()
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        mint: hookMint.publicKey,
+        transferApproval,
+        extraAccountMetaList,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const purchaseIx = await program.methods
+      .releasePurchase(
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: hookMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: null,
+        receiverReleaseTokenAccount: sellerReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+    const hookInitTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, hookInitIx],
+      artist,
+      lightConnection,
+      []
+    );
+    const purchaseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, purchaseIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    for (const signature of [hookInitTxid, purchaseTxid]) {
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      await lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature,
+        },
+        'finalized',
+      );
+    }
+
+    // a plain transfer without an approval is rejected by the hook
+    const createBuyerAccountIx = createAssociatedTokenAccountInstruction(
+      payer.publicKey,
+      buyerReleaseTokenAccount,
+      payer.publicKey,
+      hookMint.publicKey,
+      TOKEN_2022_PROGRAM_ID,
+      ASSOCIATED_PROGRAM_ID
+    );
+    const unapprovedTransferIx = await createTransferCheckedWithTransferHookInstruction(
+      lightConnection,
+      sellerReleaseTokenAccount,
+      hookMint.publicKey,
+      buyerReleaseTokenAccount,
+      purchaser.publicKey,
+      BigInt(1),
+      0,
+      [],
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
+    );
+    const unapprovedTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, createBuyerAccountIx, unapprovedTransferIx],
+      payer,
+      lightConnection,
+      [],
+      [purchaser]
+    );
+    const unapprovedBlockHash = await lightConnection.getLatestBlockhash();
+    const unapprovedResult = await lightConnection.confirmTransaction(
+      {
+        blockhash: unapprovedBlockHash.blockhash,
+        lastValidBlockHeight: unapprovedBlockHash.lastValidBlockHeight,
+        signature: unapprovedTxid,
+      },
+      'finalized',
+    );
+    expect(unapprovedResult.value.err).to.not.be.null;
+
+    const sellerTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+
+    const approveIx = await program.methods
+      .releaseResaleApprove(new anchor.BN(resalePrice))
+      .accountsStrict({
+        payer: payer.publicKey,
+        buyer: payer.publicKey,
+        seller: purchaser.publicKey,
+        release,
+        mint: hookMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        buyerPaymentTokenAccount: payerAta,
+        sellerPaymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        sellerReleaseTokenAccount,
+        buyerReleaseTokenAccount,
+        transferApproval,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const transferIx = await createTransferCheckedWithTransferHookInstruction(
+      lightConnection,
+      sellerReleaseTokenAccount,
+      hookMint.publicKey,
+      buyerReleaseTokenAccount,
+      purchaser.publicKey,
+      BigInt(1),
+      0,
+      [],
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const resaleTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, approveIx, transferIx],
+      payer,
+      lightConnection,
+      [],
+      [purchaser]
+    );
+    const resaleBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: resaleBlockHash.blockhash,
+        lastValidBlockHeight: resaleBlockHash.lastValidBlockHeight,
+        signature: resaleTxid,
+      },
+      'finalized',
+    );
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    const royalty = resalePrice * resaleRoyalty / 1000000;
+    const sellerTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    const buyerReleaseBalance = await lightConnection.getTokenAccountBalance(buyerReleaseTokenAccount, 'confirmed');
+    expect(Number(sellerTokenBalance.value.amount)).to.equal(Number(sellerTokenBalanceBefore.value.amount) + resalePrice - royalty);
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + royalty);
+    expect(Number(buyerReleaseBalance.value.amount)).to.equal(1);
+  });
+
+  it("List a Release for resale, cancel, relist and buy it", async () => {
+    const listedMint = Keypair.generate();
+    const resaleRoyalty = 100000;
+    const listingPrice = RELEASE_PRICE * 3;
+
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      listedMint,
+      undefined,
+      RELEASE_PRICE,
+      { transferHook: false, resaleRoyalty: new anchor.BN(resaleRoyalty), nonTransferable: false, permanentDelegate: false, mintCloseAuthority: false, additionalMetadata: [], openEdition: false, saleEnd: null, referralFee: new anchor.BN(0), tipRequiresHolder: false },
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const [listing] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-listing")),
+        listedMint.publicKey.toBuffer(),
+        purchaser.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const sellerReleaseTokenAccount = associatedAddress({
+      mint: listedMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const escrowTokenAccount = PublicKey.findProgramAddressSync(
+      [listing.toBuffer(), TOKEN_2022_PROGRAM_ID.toBuffer(), listedMint.publicKey.toBuffer()],
+      ASSOCIATED_PROGRAM_ID
+    )[0];
+    const buyerReleaseTokenAccount = associatedAddress({
+      mint: listedMint.publicKey,
+      owner: payer.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+
+    const purchaseIx = await program.methods
+      .releasePurchase(
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: listedMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: null,
+        receiverReleaseTokenAccount: sellerReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const listingCreateIx = await program.methods
+      .listingCreate(new anchor.BN(listingPrice))
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        seller: purchaser.publicKey,
+        release,
+        mint: listedMint.publicKey,
+        listing,
+        sellerReleaseTokenAccount,
+        escrowTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const listingCancelIx = await program.methods
+      .listingCancel()
+      .accountsStrict({
+        seller: purchaser.publicKey,
+        listing,
+        mint: listedMint.publicKey,
+        sellerReleaseTokenAccount,
+        escrowTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+    const listTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, purchaseIx, listingCreateIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const listBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: listBlockHash.blockhash,
+        lastValidBlockHeight: listBlockHash.lastValidBlockHeight,
+        signature: listTxid,
+      },
+      'finalized',
+    );
+    const escrowBalance = await lightConnection.getTokenAccountBalance(escrowTokenAccount, 'confirmed');
+    expect(Number(escrowBalance.value.amount)).to.equal(1);
+
+    const cancelTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, listingCancelIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const cancelBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: cancelBlockHash.blockhash,
+        lastValidBlockHeight: cancelBlockHash.lastValidBlockHeight,
+        signature: cancelTxid,
+      },
+      'finalized',
+    );
+    const sellerReleaseBalance = await lightConnection.getTokenAccountBalance(sellerReleaseTokenAccount, 'confirmed');
+    expect(Number(sellerReleaseBalance.value.amount)).to.equal(1);
+    expect(await lightConnection.getAccountInfo(listing, 'confirmed')).to.be.null;
+
+    const relistTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, listingCreateIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const relistBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: relistBlockHash.blockhash,
+        lastValidBlockHeight: relistBlockHash.lastValidBlockHeight,
+        signature: relistTxid,
+      },
+      'finalized',
+    );
+
+    const sellerTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+
+    const listingBuyIx = await program.methods
+      .listingBuy(new anchor.BN(listingPrice))
+      .accountsStrict({
+        payer: payer.publicKey,
+        buyer: payer.publicKey,
+        seller: purchaser.publicKey,
+        listing,
+        release,
+        mint: listedMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        buyerPaymentTokenAccount: payerAta,
+        sellerPaymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        escrowTokenAccount,
+        buyerReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const buyTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, listingBuyIx],
+      payer,
+      lightConnection,
+      []
+    );
+    const buyBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: buyBlockHash.blockhash,
+        lastValidBlockHeight: buyBlockHash.lastValidBlockHeight,
+        signature: buyTxid,
+      },
+      'finalized',
+    );
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    const royalty = listingPrice * resaleRoyalty / 1000000;
+    const sellerTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    const buyerReleaseBalance = await lightConnection.getTokenAccountBalance(buyerReleaseTokenAccount, 'confirmed');
+    expect(Number(sellerTokenBalance.value.amount)).to.equal(Number(sellerTokenBalanceBefore.value.amount) + listingPrice - royalty);
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + royalty);
+    expect(Number(buyerReleaseBalance.value.amount)).to.equal(1);
+    expect(await lightConnection.getAccountInfo(escrowTokenAccount, 'confirmed')).to.be.null;
+  });
+
+  it("Purchase a non-transferable Release and fail to transfer it", async () => {
+    const soulboundMint = Keypair.generate();
+
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      soulboundMint,
+      undefined,
+      RELEASE_PRICE,
+      { transferHook: false, resaleRoyalty: new anchor.BN(0), nonTransferable: true, permanentDelegate: false, mintCloseAuthority: false, additionalMetadata: [], openEdition: false, saleEnd: null, referralFee: new anchor.BN(0), tipRequiresHolder: false },
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const purchaserReleaseTokenAccount = associatedAddress({
+      mint: soulboundMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const payerReleaseTokenAccount = associatedAddress({
+      mint: soulboundMint.publicKey,
+      owner: payer.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+
+    const purchaseIx = await program.methods
+      .releasePurchase(
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: soulboundMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: null,
+        receiverReleaseTokenAccount: purchaserReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const purchaseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, purchaseIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const purchaseBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: purchaseBlockHash.blockhash,
+        lastValidBlockHeight: purchaseBlockHash.lastValidBlockHeight,
+        signature: purchaseTxid,
+      },
+      'finalized',
+    );
+    const purchaserReleaseBalance = await lightConnection.getTokenAccountBalance(purchaserReleaseTokenAccount, 'confirmed');
+    expect(Number(purchaserReleaseBalance.value.amount)).to.equal(1);
+
+    const createPayerAccountIx = createAssociatedTokenAccountInstruction(
+      purchaser.publicKey,
+      payerReleaseTokenAccount,
+      payer.publicKey,
+      soulboundMint.publicKey,
+      TOKEN_2022_PROGRAM_ID,
+      ASSOCIATED_PROGRAM_ID
+    );
+    const transferIx = createTransferCheckedInstruction(
+      purchaserReleaseTokenAccount,
+      soulboundMint.publicKey,
+      payerReleaseTokenAccount,
+      purchaser.publicKey,
+      1,
+      0,
+      [],
+      TOKEN_2022_PROGRAM_ID
+    );
+    const transferTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, createPayerAccountIx, transferIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const transferBlockHash = await lightConnection.getLatestBlockhash();
+    const transferResult = await lightConnection.confirmTransaction(
+      {
+        blockhash: transferBlockHash.blockhash,
+        lastValidBlockHeight: transferBlockHash.lastValidBlockHeight,
+        signature: transferTxid,
+      },
+      'finalized',
+    );
+    expect(transferResult.value.err).to.not.be.null;
+    const purchaserReleaseBalanceAfter = await lightConnection.getTokenAccountBalance(purchaserReleaseTokenAccount, 'confirmed');
+    expect(Number(purchaserReleaseBalanceAfter.value.amount)).to.equal(1);
+  });
+
+  it("Revoke a Release token and close the mint", async () => {
+    const revocableMint = Keypair.generate();
+
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      revocableMint,
+      undefined,
+      RELEASE_PRICE,
+      {
+        transferHook: false,
+        resaleRoyalty: new anchor.BN(0),
+        nonTransferable: false,
+        permanentDelegate: true,
+        mintCloseAuthority: true,
+        additionalMetadata: [],
+        openEdition: false,
+        saleEnd: null,
+        referralFee: new anchor.BN(0),
+        tipRequiresHolder: false,
+      },
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const purchaserReleaseTokenAccount = associatedAddress({
+      mint: revocableMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+
+    const purchaseIx = await program.methods
+      .releasePurchase(
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: revocableMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: null,
+        receiverReleaseTokenAccount: purchaserReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const purchaseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, purchaseIx],
+      purchaser,
+      lightConnection,
+      []
+    );
+    const purchaseBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: purchaseBlockHash.blockhash,
+        lastValidBlockHeight: purchaseBlockHash.lastValidBlockHeight,
+        signature: purchaseTxid,
+      },
+      'finalized',
+    );
+
+    const revokeIx = await program.methods
+      .releaseTokenRevoke(releaseSignerBump)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        releaseSigner,
+        mint: revocableMint.publicKey,
+        tokenAccount: purchaserReleaseTokenAccount,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+    const closeIx = await program.methods
+      .releaseMintClose(releaseSignerBump)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        releaseSigner,
+        mint: revocableMint.publicKey,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+    const closeTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, revokeIx, closeIx],
+      artist,
+      lightConnection,
+      []
+    );
+    const closeBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: closeBlockHash.blockhash,
+        lastValidBlockHeight: closeBlockHash.lastValidBlockHeight,
+        signature: closeTxid,
+      },
+      'finalized',
+    );
+
+    const purchaserReleaseBalance = await lightConnection.getTokenAccountBalance(purchaserReleaseTokenAccount, 'confirmed');
+    expect(Number(purchaserReleaseBalance.value.amount)).to.equal(0);
+    expect(await lightConnection.getAccountInfo(revocableMint.publicKey, 'confirmed')).to.be.null;
+    expect(await lightConnection.getAccountInfo(release, 'confirmed')).to.be.null;
+  });
+
+  it("Purchase an open edition Release until its sale ends", async () => {
+    const openEditionMint = Keypair.generate();
+
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      openEditionMint,
+      undefined,
+      RELEASE_PRICE,
+      {
+        transferHook: false,
+        resaleRoyalty: new anchor.BN(0),
+        nonTransferable: false,
+        permanentDelegate: false,
+        mintCloseAuthority: false,
+        additionalMetadata: [],
+        openEdition: true,
+        saleEnd: null,
+        referralFee: new anchor.BN(0),
+        tipRequiresHolder: false,
+      },
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const purchaserReleaseTokenAccount = associatedAddress({
+      mint: openEditionMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const sendPurchase = async () => {
+      const purchaseIx = await program.methods
+        .releasePurchase(
+          new anchor.BN(RELEASE_PRICE),
+          releaseSignerBump,
+        )
+        .accountsStrict({
+          payer: purchaser.publicKey,
+          receiver: purchaser.publicKey,
+          release,
+          releaseSigner,
+          mint: openEditionMint.publicKey,
+          paymentMint: paymentMint.publicKey,
+          paymentTokenAccount: purchaserAta,
+          royaltyTokenAccount,
+          referrerTokenAccount: null,
+          receiverReleaseTokenAccount: purchaserReleaseTokenAccount,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+      const purchaseTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, purchaseIx],
+        purchaser,
+        lightConnection,
+        []
+      );
+      const purchaseBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: purchaseBlockHash.blockhash,
+          lastValidBlockHeight: purchaseBlockHash.lastValidBlockHeight,
+          signature: purchaseTxid,
+        },
+        'finalized',
+      );
+    };
+    const sendUpdate = async (totalSupply: anchor.BN | null, saleEnd: anchor.BN | null) => {
+      const updateIx = await program.methods
+        .releaseUpdatePartial({
+          uri: null,
+          name: null,
+          symbol: null,
+          price: null,
+          totalSupply,
+          additionalMetadata: null,
+          saleEnd,
+          referralFee: null,
+          tipRequiresHolder: null,
+          releaseSignerBump,
+        })
+        .accountsStrict({
+          payer: artist.publicKey,
+          authority: artist.publicKey,
+          releaseSigner,
+          release,
+          mint: openEditionMint.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          label: null,
+        })
+        .instruction();
+      const updateTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, updateIx],
+        artist,
+        lightConnection,
+        [],
+      );
+      const updateBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: updateBlockHash.blockhash,
+          lastValidBlockHeight: updateBlockHash.lastValidBlockHeight,
+          signature: updateTxid,
+        },
+        'finalized',
+      );
+    };
+
+    // total supply is ignored for open editions
+    const supplyResult = await sendUpdate(new anchor.BN(0), null);
+    expect(supplyResult.value.err).to.be.null;
+    const firstPurchase = await sendPurchase();
+    expect(firstPurchase.value.err).to.be.null;
+    const secondPurchase = await sendPurchase();
+    expect(secondPurchase.value.err).to.be.null;
+    const purchaserReleaseBalance = await lightConnection.getTokenAccountBalance(purchaserReleaseTokenAccount, 'confirmed');
+    expect(Number(purchaserReleaseBalance.value.amount)).to.equal(2);
+
+    const slot = await lightConnection.getSlot('finalized');
+    const blockTime = await lightConnection.getBlockTime(slot);
+    const saleEndResult = await sendUpdate(null, new anchor.BN(blockTime + 5));
+    expect(saleEndResult.value.err).to.be.null;
+    await new Promise((resolve) => setTimeout(resolve, 10000));
+
+    const lateResult = await sendPurchase();
+    expect(lateResult.value.err).to.not.be.null;
+    const purchaserReleaseBalanceAfter = await lightConnection.getTokenAccountBalance(purchaserReleaseTokenAccount, 'confirmed');
+    expect(Number(purchaserReleaseBalanceAfter.value.amount)).to.equal(2);
+  });
+
+  it("Reveal a Release's final metadata", async () => {
+    const revealMint = Keypair.generate();
+    const finalUri = `https://arweave.net/ZIdtfNs7XKWlIz3_n1CnfYhKNHlWgnHyM7SfNXrZ1aQ`;
+
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      revealMint,
+      undefined,
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [release.toBuffer()],
+        program.programId
+      );
+    const [revealCommitment] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-reveal")),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const revealInitIx = await program.methods
+      .releaseRevealInit(Array.from(createHash("sha256").update(finalUri).digest()))
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        mint: revealMint.publicKey,
+        revealCommitment,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        label: null,
+      })
+      .instruction();
+    const revealInitTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, revealInitIx],
+      artist,
+      lightConnection,
+      [],
+    );
+    const revealInitBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: revealInitBlockHash.blockhash,
+        lastValidBlockHeight: revealInitBlockHash.lastValidBlockHeight,
+        signature: revealInitTxid,
+      },
+      'finalized',
+    );
+
+    const sendReveal = async (uri: string) => {
+      const revealIx = await program.methods
+        .releaseReveal(uri, releaseSignerBump)
+        .accountsStrict({
+          payer: artist.publicKey,
+          authority: artist.publicKey,
+          releaseSigner,
+          release,
+          mint: revealMint.publicKey,
+          revealCommitment,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          label: null,
+        })
+        .instruction();
+      const revealTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, revealIx],
+        artist,
+        lightConnection,
+        [],
+      );
+      const revealBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: revealBlockHash.blockhash,
+          lastValidBlockHeight: revealBlockHash.lastValidBlockHeight,
+          signature: revealTxid,
+        },
+        'finalized',
+      );
+    };
+
+    const wrongReveal = await sendReveal(`https://arweave.net/not-the-committed-uri`);
+    expect(wrongReveal.value.err).to.not.be.null;
+
+    // the committed uri can't be sidestepped with a regular update before the reveal
+    const updateUriIx = await program.methods
+      .releaseUpdatePartial({
+        uri: finalUri,
+        name: null,
+        symbol: null,
+        price: null,
+        totalSupply: null,
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: null,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        releaseSigner,
+        release,
+        mint: revealMint.publicKey,
+        label: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const updateUriTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, updateUriIx],
+      artist,
+      lightConnection,
+      [],
+    );
+    const updateUriBlockHash = await lightConnection.getLatestBlockhash();
+    const updateUri = await lightConnection.confirmTransaction(
+      {
+        blockhash: updateUriBlockHash.blockhash,
+        lastValidBlockHeight: updateUriBlockHash.lastValidBlockHeight,
+        signature: updateUriTxid,
+      },
+      'finalized',
+    );
+    expect(updateUri.value.err).to.not.be.null;
+
+    const placeholderMetadata = await getTokenMetadata(lightConnection, revealMint.publicKey, 'confirmed');
+    expect(placeholderMetadata.uri).to.equal(`https://arweave.net/rb9wx261pn2nCbiHtoqR2vQtZ3MRQ3qcyZeSSCE0Rm4`);
+
+    const reveal = await sendReveal(finalUri);
+    expect(reveal.value.err).to.be.null;
+    const metadata = await getTokenMetadata(lightConnection, revealMint.publicKey, 'confirmed');
+    expect(metadata.uri).to.equal(finalUri);
+    expect(await lightConnection.getAccountInfo(revealCommitment, 'confirmed')).to.be.null;
+    expect((await program.account.releaseV2.fetch(release)).revealPending).to.be.false;
+  });
+
+  it("Crowdfund a Release and finalize it once the goal is met", async () => {
+    const { release, crowdfund, crowdfundMint, escrowTokenAccount } = await setupCrowdfundRelease(
+      payer,
+      artist,
+      paymentMint,
+      1,
+      600,
+    );
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+
+    const purchase = await sendCrowdfundPurchase(
+      purchaser,
+      purchaserAta,
+      release,
+      crowdfund,
+      crowdfundMint.publicKey,
+      paymentMint.publicKey,
+      escrowTokenAccount,
+    );
+    expect(purchase.value.err).to.be.null;
+    const escrowBalance = await lightConnection.getTokenAccountBalance(escrowTokenAccount, 'confirmed');
+    expect(Number(escrowBalance.value.amount)).to.equal(RELEASE_PRICE);
+
+    const finalizeIx = await program.methods
+      .crowdfundFinalize()
+      .accountsStrict({
+        authority: artist.publicKey,
+        release,
+        crowdfund,
+        paymentMint: paymentMint.publicKey,
+        escrowTokenAccount,
+        royaltyTokenAccount,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    const finalizeTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, finalizeIx],
+      payer,
+      lightConnection,
+      [],
+    );
+    const finalizeBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: finalizeBlockHash.blockhash,
+        lastValidBlockHeight: finalizeBlockHash.lastValidBlockHeight,
+        signature: finalizeTxid,
+      },
+      'finalized',
+    );
+
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + RELEASE_PRICE);
+    const crowdfundData = await program.account.crowdfund.fetch(crowdfund);
+    expect(crowdfundData.finalized).to.be.true;
+    const releaseData = await program.account.releaseV2.fetch(release);
+    expect(releaseData.crowdfunding).to.be.false;
+  });
+
+  it("Refund a Release when its crowdfund misses the goal", async () => {
+    const { release, crowdfund, crowdfundMint, escrowTokenAccount } = await setupCrowdfundRelease(
+      payer,
+      artist,
+      paymentMint,
+      10,
+      5,
+    );
+    const purchaserTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+
+    const purchase = await sendCrowdfundPurchase(
+      purchaser,
+      purchaserAta,
+      release,
+      crowdfund,
+      crowdfundMint.publicKey,
+      paymentMint.publicKey,
+      escrowTokenAccount,
+    );
+    expect(purchase.value.err).to.be.null;
+    await new Promise((resolve) => setTimeout(resolve, 10000));
+
+    const [contribution] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-contribution")),
+        crowdfund.toBuffer(),
+        purchaser.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const buyerReleaseTokenAccount = associatedAddress({
+      mint: crowdfundMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const refundIx = await program.methods
+      .releaseRefund()
+      .accountsStrict({
+        buyer: purchaser.publicKey,
+        release,
+        mint: crowdfundMint.publicKey,
+        crowdfund,
+        contribution,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        escrowTokenAccount,
+        buyerReleaseTokenAccount,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const refundTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, refundIx],
+      purchaser,
+      lightConnection,
+      [],
+    );
+    const refundBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: refundBlockHash.blockhash,
+        lastValidBlockHeight: refundBlockHash.lastValidBlockHeight,
+        signature: refundTxid,
+      },
+      'finalized',
+    );
+
+    const purchaserTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    expect(Number(purchaserTokenBalance.value.amount)).to.equal(Number(purchaserTokenBalanceBefore.value.amount));
+    const buyerReleaseBalance = await lightConnection.getTokenAccountBalance(buyerReleaseTokenAccount, 'confirmed');
+    expect(Number(buyerReleaseBalance.value.amount)).to.equal(0);
+    expect(await lightConnection.getAccountInfo(contribution, 'confirmed')).to.be.null;
+  });
+
+  it("Pre-order a Release and fulfill it after the release date", async () => {
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    const {
+      release,
+      releaseSigner,
+      releaseSignerBump,
+      preorderMint,
+      escrowTokenAccount,
+      preorderReceipt,
+    } = await setupPreorderRelease(payer, artist, paymentMint, 5);
+
+    const escrowBalance = await lightConnection.getTokenAccountBalance(escrowTokenAccount, 'confirmed');
+    expect(Number(escrowBalance.value.amount)).to.equal(RELEASE_PRICE);
+    const releaseData = await program.account.releaseV2.fetch(release);
+    expect(releaseData.preordersOutstanding.toNumber()).to.equal(1);
+
+    // a pre-order release can't also be crowdfunded
+    const [crowdfund] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-crowdfund")),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+    const slot = await lightConnection.getSlot('finalized');
+    const blockTime = await lightConnection.getBlockTime(slot);
+    const crowdfundInitIx = await program.methods
+      .crowdfundInit(
+        new anchor.BN(0),
+        new anchor.BN(1),
+        new anchor.BN(blockTime + 600),
+      )
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        mint: preorderMint.publicKey,
+        crowdfund,
+        paymentMint: paymentMint.publicKey,
+        escrowTokenAccount: associatedAddress({
+          mint: paymentMint.publicKey,
+          owner: crowdfund,
+        }),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+    const crowdfundInitTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, crowdfundInitIx],
+      artist,
+      lightConnection,
+      [],
+    );
+    const crowdfundInitBlockHash = await lightConnection.getLatestBlockhash();
+    const crowdfundInit = await lightConnection.confirmTransaction(
+      {
+        blockhash: crowdfundInitBlockHash.blockhash,
+        lastValidBlockHeight: crowdfundInitBlockHash.lastValidBlockHeight,
+        signature: crowdfundInitTxid,
+      },
+      'finalized',
+    );
+    expect(crowdfundInit.value.err).to.not.be.null;
+
+    // with the only copy pre-ordered, an airdrop can't take it
+    const sendAsArtist = async (ix: anchor.web3.TransactionInstruction) => {
+      const ixTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ix],
+        artist,
+        lightConnection,
+        [],
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: ixTxid,
+        },
+        'finalized',
+      );
+    };
+    const updateSupplyIx = await program.methods
+      .releaseUpdatePartial({
+        uri: null,
+        name: null,
+        symbol: null,
+        price: null,
+        totalSupply: new anchor.BN(1),
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: null,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        releaseSigner,
+        release,
+        mint: preorderMint.publicKey,
+        label: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const updateSupply = await sendAsArtist(updateSupplyIx);
+    expect(updateSupply.value.err).to.be.null;
+    const airdropRecipient = Keypair.generate().publicKey;
+    const airdropIx = await program.methods
+      .releaseAirdrop(releaseSignerBump)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        releaseSigner,
+        mint: preorderMint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .remainingAccounts([
+        { pubkey: airdropRecipient, isSigner: false, isWritable: false },
+        {
+          pubkey: associatedAddress({
+            mint: preorderMint.publicKey,
+            owner: airdropRecipient,
+            tokenProgramId: TOKEN_2022_PROGRAM_ID,
+          }),
+          isSigner: false,
+          isWritable: true,
+        },
+      ])
+      .instruction();
+    const airdrop = await sendAsArtist(airdropIx);
+    expect(airdrop.value.err).to.not.be.null;
+    await new Promise((resolve) => setTimeout(resolve, 10000));
+
+    const buyerReleaseTokenAccount = associatedAddress({
+      mint: preorderMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const fulfillIx = await program.methods
+      .releaseFulfillPreorder(releaseSignerBump)
+      .accountsStrict({
+        payer: payer.publicKey,
+        buyer: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: preorderMint.publicKey,
+        preorderReceipt,
+        paymentMint: paymentMint.publicKey,
+        escrowTokenAccount,
+        royaltyTokenAccount,
+        buyerReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const fulfillTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, fulfillIx],
+      payer,
+      lightConnection,
+      [],
+    );
+    const fulfillBlockHash = await lightConnection.getLatestBlockhash();
+    const fulfill = await lightConnection.confirmTransaction(
+      {
+        blockhash: fulfillBlockHash.blockhash,
+        lastValidBlockHeight: fulfillBlockHash.lastValidBlockHeight,
+        signature: fulfillTxid,
+      },
+      'finalized',
+    );
+    expect(fulfill.value.err).to.be.null;
+
+    const buyerReleaseBalance = await lightConnection.getTokenAccountBalance(buyerReleaseTokenAccount, 'confirmed');
+    expect(Number(buyerReleaseBalance.value.amount)).to.equal(1);
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + RELEASE_PRICE);
+    expect(await lightConnection.getAccountInfo(preorderReceipt, 'confirmed')).to.be.null;
+  });
+
+  it("Reclaim a pre-order when the Release is cancelled", async () => {
+    const purchaserTokenBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const {
+      release,
+      releaseSigner,
+      releaseSignerBump,
+      preorderMint,
+      escrowTokenAccount,
+      preorderReceipt,
+    } = await setupPreorderRelease(payer, artist, paymentMint, 600, {
+      transferHook: false,
+      resaleRoyalty: new anchor.BN(0),
+      nonTransferable: false,
+      permanentDelegate: false,
+      mintCloseAuthority: true,
+      additionalMetadata: [],
+      openEdition: false,
+      saleEnd: null,
+      referralFee: new anchor.BN(0),
+      tipRequiresHolder: false,
+    });
+
+    // the unminted release can't be closed while it holds pre-order payments
+    const mintCloseIx = await program.methods
+      .releaseMintClose(releaseSignerBump)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        releaseSigner,
+        mint: preorderMint.publicKey,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+    const mintCloseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, mintCloseIx],
+      artist,
+      lightConnection,
+      [],
+    );
+    const mintCloseBlockHash = await lightConnection.getLatestBlockhash();
+    const mintClose = await lightConnection.confirmTransaction(
+      {
+        blockhash: mintCloseBlockHash.blockhash,
+        lastValidBlockHeight: mintCloseBlockHash.lastValidBlockHeight,
+        signature: mintCloseTxid,
+      },
+      'finalized',
+    );
+    expect(mintClose.value.err).to.not.be.null;
+    expect(await lightConnection.getAccountInfo(release, 'confirmed')).to.not.be.null;
+
+    const cancelIx = await program.methods
+      .releasePreorderCancel()
+      .accountsStrict({
+        authority: artist.publicKey,
+        release,
+        label: null,
+      })
+      .instruction();
+    const reclaimIx = await program.methods
+      .releasePreorderReclaim(releaseSignerBump)
+      .accountsStrict({
+        buyer: purchaser.publicKey,
+        release,
+        releaseSigner,
+        preorderReceipt,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        escrowTokenAccount,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    const txid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, cancelIx, reclaimIx],
+      purchaser,
+      lightConnection,
+      [],
+      [artist],
+    );
+    const latestBlockHash = await lightConnection.getLatestBlockhash();
+    const reclaim = await lightConnection.confirmTransaction(
+      {
+        blockhash: latestBlockHash.blockhash,
+        lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+    expect(reclaim.value.err).to.be.null;
+
+    const purchaserTokenBalance = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    expect(Number(purchaserTokenBalance.value.amount)).to.equal(Number(purchaserTokenBalanceBefore.value.amount));
+    const releaseData = await program.account.releaseV2.fetch(release);
+    expect(releaseData.preorderCancelled).to.be.true;
+    expect(releaseData.preordersOutstanding.toNumber()).to.equal(0);
+  });
+
+  it("Purchase a Release through a Hub that takes a fee", async () => {
+    const hubId = new anchor.BN(Date.now());
+    const [hub] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-hub")),
+        payer.publicKey.toBuffer(),
+        hubId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const hubMint = Keypair.generate();
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      hubMint,
+      undefined,
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+    const [hubRelease] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-hub-release")),
+        hub.toBuffer(),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const hubInitIx = await program.methods
+      .hubInit(hubId, new anchor.BN(100_000), "https://ninaprotocol.com/hub")
+      .accountsStrict({
+        payer: payer.publicKey,
+        authority: payer.publicKey,
+        hub,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+    const buildHubAddReleaseIx = () => program.methods
+      .hubAddRelease()
+      .accountsStrict({
+        payer: payer.publicKey,
+        authority: payer.publicKey,
+        hub,
+        release,
+        releaseAuthority: artist.publicKey,
+        hubRelease,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        label: null,
+      })
+      .instruction();
+
+    // a hub can't list a release without its artist signing off
+    const unsignedAddReleaseIx = await buildHubAddReleaseIx();
+    unsignedAddReleaseIx.keys.find((key) => key.pubkey.equals(artist.publicKey)).isSigner = false;
+    const unsignedTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, hubInitIx, unsignedAddReleaseIx],
+      payer,
+      lightConnection,
+      [],
+    );
+    const unsignedBlockHash = await lightConnection.getLatestBlockhash();
+    const unsigned = await lightConnection.confirmTransaction(
+      {
+        blockhash: unsignedBlockHash.blockhash,
+        lastValidBlockHeight: unsignedBlockHash.lastValidBlockHeight,
+        signature: unsignedTxid,
+      },
+      'finalized',
+    );
+    expect(unsigned.value.err).to.not.be.null;
+
+    const hubTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, hubInitIx, await buildHubAddReleaseIx()],
+      payer,
+      lightConnection,
+      [],
+      [artist],
+    );
+    const hubBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: hubBlockHash.blockhash,
+        lastValidBlockHeight: hubBlockHash.lastValidBlockHeight,
+        signature: hubTxid,
+      },
+      'finalized',
+    );
+
+    const hubTokenBalanceBefore = await lightConnection.getTokenAccountBalance(payerAta, 'confirmed');
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const purchaseIx = await program.methods
+      .hubReleasePurchase(new anchor.BN(RELEASE_PRICE), releaseSignerBump)
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        hub,
+        hubRelease,
+        release,
+        releaseSigner,
+        mint: hubMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        hubTokenAccount: payerAta,
+        receiverReleaseTokenAccount: associatedAddress({
+          mint: hubMint.publicKey,
+          owner: purchaser.publicKey,
+          tokenProgramId: TOKEN_2022_PROGRAM_ID,
+        }),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const purchaseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, purchaseIx],
+      purchaser,
+      lightConnection,
+      [],
+    );
+    const purchaseBlockHash = await lightConnection.getLatestBlockhash();
+    const purchase = await lightConnection.confirmTransaction(
+      {
+        blockhash: purchaseBlockHash.blockhash,
+        lastValidBlockHeight: purchaseBlockHash.lastValidBlockHeight,
+        signature: purchaseTxid,
+      },
+      'finalized',
+    );
+    expect(purchase.value.err).to.be.null;
+
+    const hubFee = RELEASE_PRICE / 10;
+    const hubTokenBalance = await lightConnection.getTokenAccountBalance(payerAta, 'confirmed');
+    expect(Number(hubTokenBalance.value.amount)).to.equal(Number(hubTokenBalanceBefore.value.amount) + hubFee);
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + RELEASE_PRICE - hubFee);
+
+    const hubRemoveReleaseIx = await program.methods
+      .hubRemoveRelease()
+      .accountsStrict({
+        authority: payer.publicKey,
+        hub,
+        hubRelease,
+      })
+      .instruction();
+    const removeTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, hubRemoveReleaseIx],
+      payer,
+      lightConnection,
+      [],
+    );
+    const removeBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: removeBlockHash.blockhash,
+        lastValidBlockHeight: removeBlockHash.lastValidBlockHeight,
+        signature: removeTxid,
+      },
+      'finalized',
+    );
+    expect(await lightConnection.getAccountInfo(hubRelease, 'confirmed')).to.be.null;
+  });
+
+  it("Purchase a Release with a referrer who earns the referral fee", async () => {
+    const referralFee = 50000;
+    const referredMint = Keypair.generate();
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      referredMint,
+      undefined,
+      RELEASE_PRICE,
+      { transferHook: false, resaleRoyalty: new anchor.BN(0), nonTransferable: false, permanentDelegate: false, mintCloseAuthority: false, additionalMetadata: [], openEdition: false, saleEnd: null, referralFee: new anchor.BN(referralFee), tipRequiresHolder: false },
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const referrerTokenBalanceBefore = await lightConnection.getTokenAccountBalance(payerAta, 'confirmed');
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const ix = await program.methods
+      .releasePurchase(
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: referredMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: payerAta,
+        receiverReleaseTokenAccount: associatedAddress({
+          mint: referredMint.publicKey,
+          owner: purchaser.publicKey,
+          tokenProgramId: TOKEN_2022_PROGRAM_ID,
+        }),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const purchaseTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, ix],
+      purchaser,
+      lightConnection,
+      [],
+    );
+    const purchaseBlockHash = await lightConnection.getLatestBlockhash();
+    const purchase = await lightConnection.confirmTransaction(
+      {
+        blockhash: purchaseBlockHash.blockhash,
+        lastValidBlockHeight: purchaseBlockHash.lastValidBlockHeight,
+        signature: purchaseTxid,
+      },
+      'finalized',
+    );
+    expect(purchase.value.err).to.be.null;
+
+    const referralAmount = RELEASE_PRICE * referralFee / 1_000_000;
+    const referrerTokenBalance = await lightConnection.getTokenAccountBalance(payerAta, 'confirmed');
+    expect(Number(referrerTokenBalance.value.amount)).to.equal(Number(referrerTokenBalanceBefore.value.amount) + referralAmount);
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + RELEASE_PRICE - referralAmount);
+  });
+
+  it("Create a Profile and link a new Release to it", async () => {
+    const [profile] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-profile")),
+        artist.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const profileInitIx = await program.methods
+      .profileInit("nina-artist", "https://ninaprotocol.com/nina-artist", royaltyTokenAccount)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        profile,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+    const profileTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, profileInitIx],
+      artist,
+      lightConnection,
+      [],
+    );
+    const profileBlockHash = await lightConnection.getLatestBlockhash();
+    const profileInit = await lightConnection.confirmTransaction(
+      {
+        blockhash: profileBlockHash.blockhash,
+        lastValidBlockHeight: profileBlockHash.lastValidBlockHeight,
+        signature: profileTxid,
+      },
+      'finalized',
+    );
+    expect(profileInit.value.err).to.be.null;
+
+    const profiledMint = Keypair.generate();
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      profiledMint,
+      undefined,
+      RELEASE_PRICE,
+      undefined,
+      profile,
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseProfile] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release-profile")),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+    const releaseProfileData = await program.account.releaseProfile.fetch(releaseProfile);
+    expect(releaseProfileData.profile.toBase58()).to.equal(profile.toBase58());
+    const profileData = await program.account.profile.fetch(profile);
+    expect(profileData.handle).to.equal("nina-artist");
+    expect(profileData.verified).to.be.false;
+    expect(profileData.payoutTokenAccount.toBase58()).to.equal(royaltyTokenAccount.toBase58());
+
+    // only the identity service can verify
+    const verifyIx = await program.methods
+      .profileVerify(true)
+      .accountsStrict({
+        verifier: artist.publicKey,
+        profile,
+      })
+      .instruction();
+    const verifyTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, verifyIx],
+      artist,
+      lightConnection,
+      [],
+    );
+    const verifyBlockHash = await lightConnection.getLatestBlockhash();
+    const verify = await lightConnection.confirmTransaction(
+      {
+        blockhash: verifyBlockHash.blockhash,
+        lastValidBlockHeight: verifyBlockHash.lastValidBlockHeight,
+        signature: verifyTxid,
+      },
+      'finalized',
+    );
+    expect(verify.value.err).to.not.be.null;
+  });
+
+  it("Let a Label manager update a Release only within their roles", async () => {
+    const LABEL_ROLE_PRICE = 2;
+    const labelId = new anchor.BN(Date.now());
+    const [label] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-label")),
+        payer.publicKey.toBuffer(),
+        labelId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const labelMint = Keypair.generate();
+    const [release] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        labelMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const labelTokenAccount = associatedAddress({
+      mint: paymentMint.publicKey,
+      owner: label,
+    });
+
+    const labelInitIx = await program.methods
+      .labelInit(labelId)
+      .accountsStrict({
+        payer: payer.publicKey,
+        authority: payer.publicKey,
+        label,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+    const setManagerIx = await program.methods
+      .labelSetManager(purchaser.publicKey, LABEL_ROLE_PRICE)
+      .accountsStrict({
+        authority: payer.publicKey,
+        label,
+      })
+      .instruction();
+    const labelTokenAccountIx = createAssociatedTokenAccountInstruction(
+      payer.publicKey,
+      labelTokenAccount,
+      label,
+      paymentMint.publicKey,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_PROGRAM_ID
+    );
+    const releaseInitIx = await program.methods
+      .releaseInitV2(
+        `https://arweave.net/rb9wx261pn2nCbiHtoqR2vQtZ3MRQ3qcyZeSSCE0Rm4`,
+        "Nina Label",
+        "NINA",
+        new anchor.BN(100),
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: payer.publicKey,
+        authority: label,
+        release,
+        mint: labelMint.publicKey,
+        releaseSigner,
+        paymentMint: paymentMint.publicKey,
+        royaltyTokenAccount: labelTokenAccount,
+        profile: null,
+        releaseProfile: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const initTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, labelInitIx, setManagerIx, labelTokenAccountIx, releaseInitIx],
+      payer,
+      lightConnection,
+      [],
+      [labelMint],
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    const init = await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: initTxid,
+      },
+      'finalized',
+    );
+    expect(init.value.err).to.be.null;
+
+    const sendAsManager = async (ix: anchor.web3.TransactionInstruction) => {
+      const txid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ix],
+        purchaser,
+        lightConnection,
+        [],
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: txid,
+        },
+        'finalized',
+      );
+    };
+
+    const updatePriceIx = await program.methods
+      .releaseUpdatePartial({
+        uri: null,
+        name: null,
+        symbol: null,
+        price: new anchor.BN(RELEASE_PRICE * 2),
+        totalSupply: null,
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: null,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        authority: purchaser.publicKey,
+        releaseSigner,
+        release,
+        mint: labelMint.publicKey,
+        label,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    // the manager's key alone, without their signature, is not enough
+    const unsignedUpdateIx = await program.methods
+      .releaseUpdatePartial({
+        uri: null,
+        name: null,
+        symbol: null,
+        price: new anchor.BN(0),
+        totalSupply: null,
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: null,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: payer.publicKey,
+        authority: purchaser.publicKey,
+        releaseSigner,
+        release,
+        mint: labelMint.publicKey,
+        label,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    unsignedUpdateIx.keys.find((key) => key.pubkey.equals(purchaser.publicKey)).isSigner = false;
+    const unsignedUpdateTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, unsignedUpdateIx],
+      payer,
+      lightConnection,
+      [],
+    );
+    const unsignedUpdateBlockHash = await lightConnection.getLatestBlockhash();
+    const unsignedUpdate = await lightConnection.confirmTransaction(
+      {
+        blockhash: unsignedUpdateBlockHash.blockhash,
+        lastValidBlockHeight: unsignedUpdateBlockHash.lastValidBlockHeight,
+        signature: unsignedUpdateTxid,
+      },
+      'finalized',
+    );
+    expect(unsignedUpdate.value.err).to.not.be.null;
+
+    // release_update takes the label as authority and needs the manager to sign
+    const unsignedManagerUpdateIx = await program.methods
+      .releaseUpdate(
+        `https://arweave.net/rb9wx261pn2nCbiHtoqR2vQtZ3MRQ3qcyZeSSCE0Rm4`,
+        "Nina Label",
+        "NINA",
+        releaseSignerBump,
+        new anchor.BN(0),
+        new anchor.BN(100),
+        [],
+      )
+      .accountsStrict({
+        payer: payer.publicKey,
+        authority: label,
+        releaseSigner,
+        release,
+        mint: labelMint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label,
+        manager: purchaser.publicKey,
+      })
+      .instruction();
+    unsignedManagerUpdateIx.keys.find((key) => key.pubkey.equals(purchaser.publicKey)).isSigner = false;
+    const unsignedManagerUpdateTxid = await buildSignAndSendTransaction(
+      [modifyComputeUnits, addPriorityFee, unsignedManagerUpdateIx],
+      payer,
+      lightConnection,
+      [],
+    );
+    const unsignedManagerUpdateBlockHash = await lightConnection.getLatestBlockhash();
+    const unsignedManagerUpdate = await lightConnection.confirmTransaction(
+      {
+        blockhash: unsignedManagerUpdateBlockHash.blockhash,
+        lastValidBlockHeight: unsignedManagerUpdateBlockHash.lastValidBlockHeight,
+        signature: unsignedManagerUpdateTxid,
+      },
+      'finalized',
+    );
+    expect(unsignedManagerUpdate.value.err).to.not.be.null;
+
+    const updatePrice = await sendAsManager(updatePriceIx);
+    expect(updatePrice.value.err).to.be.null;
+    const releaseData = await program.account.releaseV2.fetch(release);
+    expect(releaseData.price.toNumber()).to.equal(RELEASE_PRICE * 2);
+
+    // the other release instructions take the label the same way
+    const [claimConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-claim-config")),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+    const configureIx = await program.methods
+      .releaseClaimConfigure(new anchor.BN(1), false)
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        authority: purchaser.publicKey,
+        release,
+        claimConfig,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        label,
+      })
+      .instruction();
+    const configure = await sendAsManager(configureIx);
+    expect(configure.value.err).to.be.null;
+    const claimConfigData = await program.account.claimConfig.fetch(claimConfig);
+    expect(claimConfigData.walletLimit.toNumber()).to.equal(1);
+
+    const closeIx = await program.methods
+      .releaseClose()
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        authority: purchaser.publicKey,
+        releaseSigner,
+        release,
+        mint: labelMint.publicKey,
+        label,
+      })
+      .instruction();
+    const close = await sendAsManager(closeIx);
+    expect(close.value.err).to.not.be.null;
+  });
+
+  it("Update a Release price through a 2-of-2 multisig", async () => {
+    const multisigMint = Keypair.generate();
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      multisigMint,
+      undefined,
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [multisig] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-multisig")),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+    const [proposal] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-proposal")),
+        multisig.toBuffer(),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const sendAndConfirm = async (ix: anchor.web3.TransactionInstruction, signer: Keypair) => {
+      const ixTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ix],
+        signer,
+        lightConnection,
+        [],
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: ixTxid,
+        },
+        'finalized',
+      );
+    };
+
+    const multisigInitIx = await program.methods
+      .multisigInit([artist.publicKey, purchaser.publicKey], 2)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        multisig,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+    const multisigInit = await sendAndConfirm(multisigInitIx, artist);
+    expect(multisigInit.value.err).to.be.null;
+
+    // the multisig PDA can never sign, so updates have to go through a proposal
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const bypassIx = await program.methods
+      .releaseUpdatePartial({
+        uri: null,
+        name: null,
+        symbol: null,
+        price: new anchor.BN(0),
+        totalSupply: null,
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: null,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: payer.publicKey,
+        authority: multisig,
+        releaseSigner,
+        release,
+        mint: multisigMint.publicKey,
+        label: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    bypassIx.keys.find((key) => key.pubkey.equals(multisig)).isSigner = false;
+    const bypass = await sendAndConfirm(bypassIx, payer);
+    expect(bypass.value.err).to.not.be.null;
+    expect((await program.account.releaseV2.fetch(release)).price.toNumber()).to.equal(RELEASE_PRICE);
+
+    const proposeIx = await program.methods
+      .multisigPropose({ update: { price: new anchor.BN(RELEASE_PRICE * 3), totalSupply: null } })
+      .accountsStrict({
+        member: artist.publicKey,
+        multisig,
+        proposal,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+    const propose = await sendAndConfirm(proposeIx, artist);
+    expect(propose.value.err).to.be.null;
+
+    const executeIx = await program.methods
+      .multisigExecute()
+      .accountsStrict({
+        multisig,
+        proposal,
+        release,
+        mint: multisigMint.publicKey,
+        royaltyTokenAccount: null,
+      })
+      .instruction();
+    const earlyExecute = await sendAndConfirm(executeIx, artist);
+    expect(earlyExecute.value.err).to.not.be.null;
+
+    const approveIx = await program.methods
+      .multisigApprove()
+      .accountsStrict({
+        member: purchaser.publicKey,
+        multisig,
+        proposal,
+      })
+      .instruction();
+    const approve = await sendAndConfirm(approveIx, purchaser);
+    expect(approve.value.err).to.be.null;
+
+    const execute = await sendAndConfirm(executeIx, purchaser);
+    expect(execute.value.err).to.be.null;
+    const releaseData = await program.account.releaseV2.fetch(release);
+    expect(releaseData.price.toNumber()).to.equal(RELEASE_PRICE * 3);
+    expect(releaseData.authority.toBase58()).to.equal(multisig.toBase58());
+
+    // the members can hand the release back to a single authority
+    const [handBackProposal] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-proposal")),
+        multisig.toBuffer(),
+        new anchor.BN(1).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const handBackProposeIx = await program.methods
+      .multisigPropose({ setAuthority: { authority: artist.publicKey } })
+      .accountsStrict({
+        member: artist.publicKey,
+        multisig,
+        proposal: handBackProposal,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+    const handBackPropose = await sendAndConfirm(handBackProposeIx, artist);
+    expect(handBackPropose.value.err).to.be.null;
+    const handBackApproveIx = await program.methods
+      .multisigApprove()
+      .accountsStrict({
+        member: purchaser.publicKey,
+        multisig,
+        proposal: handBackProposal,
+      })
+      .instruction();
+    const handBackApprove = await sendAndConfirm(handBackApproveIx, purchaser);
+    expect(handBackApprove.value.err).to.be.null;
+    const handBackExecuteIx = await program.methods
+      .multisigExecute()
+      .accountsStrict({
+        multisig,
+        proposal: handBackProposal,
+        release,
+        mint: multisigMint.publicKey,
+        royaltyTokenAccount: null,
+      })
+      .instruction();
+    const handBackExecute = await sendAndConfirm(handBackExecuteIx, purchaser);
+    expect(handBackExecute.value.err).to.be.null;
+    const handedBackData = await program.account.releaseV2.fetch(release);
+    expect(handedBackData.authority.toBase58()).to.equal(artist.publicKey.toBase58());
+  });
+
+  it("Tip a Release that only accepts tips from holders", async () => {
+    const tipAmount = 2500000;
+    const tippedMint = Keypair.generate();
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      tippedMint,
+      undefined,
+      RELEASE_PRICE,
+      { transferHook: false, resaleRoyalty: new anchor.BN(0), nonTransferable: false, permanentDelegate: false, mintCloseAuthority: false, additionalMetadata: [], openEdition: false, saleEnd: null, referralFee: new anchor.BN(0), tipRequiresHolder: true },
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const tipperReleaseTokenAccount = associatedAddress({
+      mint: tippedMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const buildTipIx = (tipperReleaseTokenAccount: PublicKey | null) => program.methods
+      .releaseTip(new anchor.BN(tipAmount))
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        tipper: purchaser.publicKey,
+        release,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        tipperReleaseTokenAccount,
+        releaseTier: null,
+        holderMint: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    const sendAndConfirm = async (instructions: anchor.web3.TransactionInstruction[]) => {
+      const ixTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ...instructions],
+        purchaser,
+        lightConnection,
+        [],
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: ixTxid,
+        },
+        'finalized',
+      );
+    };
+
+    const tipWithoutHolding = await sendAndConfirm([await buildTipIx(null)]);
+    expect(tipWithoutHolding.value.err).to.not.be.null;
+
+    const purchaseIx = await program.methods
+      .releasePurchase(
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: tippedMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: null,
+        receiverReleaseTokenAccount: tipperReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    const purchaseAndTip = await sendAndConfirm([purchaseIx, await buildTipIx(tipperReleaseTokenAccount)]);
+    expect(purchaseAndTip.value.err).to.be.null;
+
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + RELEASE_PRICE + tipAmount);
+  });
+
+  it("Claim a free Release up to the per-wallet limit", async () => {
+    const freeMint = Keypair.generate();
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      freeMint,
+      undefined,
+      0,
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const [claimConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-claim-config")),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+    const [claimRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-claim")),
+        release.toBuffer(),
+        purchaser.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const receiverReleaseTokenAccount = associatedAddress({
+      mint: freeMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const sendAndConfirm = async (ix: anchor.web3.TransactionInstruction, signer: Keypair) => {
+      const ixTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ix],
+        signer,
+        lightConnection,
+        [],
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: ixTxid,
+        },
+        'finalized',
+      );
+    };
+
+    const configureIx = await program.methods
+      .releaseClaimConfigure(new anchor.BN(1), false)
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        claimConfig,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        label: null,
+      })
+      .instruction();
+    const configure = await sendAndConfirm(configureIx, artist);
+    expect(configure.value.err).to.be.null;
+
+    const buildClaimIx = (nonce: number) => program.methods
+      .releaseClaim(releaseSignerBump, new anchor.BN(0), new anchor.BN(nonce))
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: freeMint.publicKey,
+        claimConfig,
+        claimRecord,
+        voucherRedemption: null,
+        instructions: null,
+        receiverReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+    const claim = await sendAndConfirm(await buildClaimIx(0), purchaser);
+    expect(claim.value.err).to.be.null;
+    const releaseTokenBalance = await lightConnection.getTokenAccountBalance(receiverReleaseTokenAccount, 'confirmed');
+    expect(Number(releaseTokenBalance.value.amount)).to.equal(1);
+
+    const secondClaim = await sendAndConfirm(await buildClaimIx(1), purchaser);
+    expect(secondClaim.value.err).to.not.be.null;
+
+    // release_purchase still hands out a free release, without a payment transfer
+    const paymentBalanceBefore = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    const freePurchaseIx = await program.methods
+      .releasePurchase(
+        new anchor.BN(0),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: freeMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: null,
+        receiverReleaseTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const freePurchase = await sendAndConfirm(freePurchaseIx, purchaser);
+    expect(freePurchase.value.err).to.be.null;
+    const releaseTokenBalanceAfter = await lightConnection.getTokenAccountBalance(receiverReleaseTokenAccount, 'confirmed');
+    expect(Number(releaseTokenBalanceAfter.value.amount)).to.equal(2);
+    const paymentBalanceAfter = await lightConnection.getTokenAccountBalance(purchaserAta, 'confirmed');
+    expect(paymentBalanceAfter.value.amount).to.equal(paymentBalanceBefore.value.amount);
+  });
+
+  it("Purchase a limited deluxe tier of a Release", async () => {
+    const tieredMint = Keypair.generate();
+    const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      tieredMint,
+      undefined,
+    );
+    const initBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: initBlockHash.blockhash,
+        lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+        signature: txid,
+      },
+      'finalized',
+    );
+
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const tierIndex = 1;
+    const tierPrice = RELEASE_PRICE * 2;
+    const [releaseTier] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-tier")),
+        release.toBuffer(),
+        Buffer.from([tierIndex]),
+      ],
+      program.programId
+    );
+    const tierMint = Keypair.generate();
+    const receiverTierTokenAccount = associatedAddress({
+      mint: tierMint.publicKey,
+      owner: purchaser.publicKey,
+      tokenProgramId: TOKEN_2022_PROGRAM_ID,
+    });
+    const sendAndConfirm = async (
+      ix: anchor.web3.TransactionInstruction,
+      signer: Keypair,
+      additionalSigners: Keypair[] = [],
+    ) => {
+      const ixTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ix],
+        signer,
+        lightConnection,
+        [],
+        additionalSigners,
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: ixTxid,
+        },
+        'finalized',
+      );
+    };
+
+    const buildTierInitIx = (
+      tierRelease: PublicKey,
+      tierReleaseSigner: PublicKey,
+      tierReleaseSignerBump: number,
+      releaseMint: PublicKey,
+      tier: PublicKey,
+      mint: PublicKey,
+    ) => program.methods
+      .releaseTierInit({
+        tierIndex,
+        uri: "https://arweave.net/deluxe",
+        name: "Nina Deluxe",
+        symbol: "NINA",
+        price: new anchor.BN(tierPrice),
+        totalSupply: new anchor.BN(1),
+        releaseSignerBump: tierReleaseSignerBump,
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release: tierRelease,
+        releaseSigner: tierReleaseSigner,
+        mint: releaseMint,
+        releaseTier: tier,
+        tierMint: mint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        label: null,
+      })
+      .instruction();
+    const tierInitIx = await buildTierInitIx(
+      release,
+      releaseSigner,
+      releaseSignerBump,
+      tieredMint.publicKey,
+      releaseTier,
+      tierMint.publicKey,
+    );
+    const tierInit = await sendAndConfirm(tierInitIx, artist, [tierMint]);
+    expect(tierInit.value.err).to.be.null;
+
+    // a soulbound release can't be split into transferable tiers
+    const soulboundMint = Keypair.generate();
+    const { release: soulboundRelease, txid: soulboundTxid } = await buildAndSendReleaseInitV2Transaction(
+      program,
+      payer,
+      artist,
+      lightConnection,
+      paymentMint,
+      soulboundMint,
+      undefined,
+      RELEASE_PRICE,
+      { transferHook: false, resaleRoyalty: new anchor.BN(0), nonTransferable: true, permanentDelegate: false, mintCloseAuthority: false, additionalMetadata: [], openEdition: false, saleEnd: null, referralFee: new anchor.BN(0), tipRequiresHolder: false },
+    );
+    const soulboundBlockHash = await lightConnection.getLatestBlockhash();
+    await lightConnection.confirmTransaction(
+      {
+        blockhash: soulboundBlockHash.blockhash,
+        lastValidBlockHeight: soulboundBlockHash.lastValidBlockHeight,
+        signature: soulboundTxid,
+      },
+      'finalized',
+    );
+    const [soulboundReleaseSigner, soulboundReleaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [soulboundRelease.toBuffer()],
+      program.programId
+    );
+    const [soulboundTier] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-tier")),
+        soulboundRelease.toBuffer(),
+        Buffer.from([tierIndex]),
+      ],
+      program.programId
+    );
+    const soulboundTierMint = Keypair.generate();
+    const soulboundTierInit = await sendAndConfirm(
+      await buildTierInitIx(
+        soulboundRelease,
+        soulboundReleaseSigner,
+        soulboundReleaseSignerBump,
+        soulboundMint.publicKey,
+        soulboundTier,
+        soulboundTierMint.publicKey,
+      ),
+      artist,
+      [soulboundTierMint],
+    );
+    expect(soulboundTierInit.value.err).to.not.be.null;
+
+    const buildPurchaseTierIx = (amount: number) => program.methods
+      .releasePurchaseTier(
+        tierIndex,
+        new anchor.BN(amount),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        releaseTier,
+        tierMint: tierMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        receiverTierTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+    const wrongAmount = await sendAndConfirm(await buildPurchaseTierIx(RELEASE_PRICE), purchaser);
+    expect(wrongAmount.value.err).to.not.be.null;
+
+    const royaltyTokenBalanceBefore = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    const purchase = await sendAndConfirm(await buildPurchaseTierIx(tierPrice), purchaser);
+    expect(purchase.value.err).to.be.null;
+    const royaltyTokenBalance = await lightConnection.getTokenAccountBalance(royaltyTokenAccount, 'confirmed');
+    expect(Number(royaltyTokenBalance.value.amount)).to.equal(Number(royaltyTokenBalanceBefore.value.amount) + tierPrice);
+    const tierTokenBalance = await lightConnection.getTokenAccountBalance(receiverTierTokenAccount, 'confirmed');
+    expect(Number(tierTokenBalance.value.amount)).to.equal(1);
+
+    const soldOut = await sendAndConfirm(await buildPurchaseTierIx(tierPrice), purchaser);
+    expect(soldOut.value.err).to.not.be.null;
+  });
+
+  it("Purchase numbered editions of a Release until it sells out", async () => {
+    const numberedMint = Keypair.generate();
+    const [release] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release")),
+        numberedMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+    const sendAndConfirm = async (
+      ix: anchor.web3.TransactionInstruction,
+      signer: Keypair,
+      additionalSigners: Keypair[] = [],
+    ) => {
+      const ixTxid = await buildSignAndSendTransaction(
+        [modifyComputeUnits, addPriorityFee, ix],
+        signer,
+        lightConnection,
+        [],
+        additionalSigners,
+      );
+      const latestBlockHash = await lightConnection.getLatestBlockhash();
+      return lightConnection.confirmTransaction(
+        {
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: ixTxid,
+        },
+        'finalized',
+      );
+    };
+
+    const numberedInitIx = await program.methods
+      .releaseNumberedInit({
+        uri: `https://arweave.net/rb9wx261pn2nCbiHtoqR2vQtZ3MRQ3qcyZeSSCE0Rm4`,
+        name: "Nina Numbered",
+        symbol: "NINA",
+        totalSupply: new anchor.BN(2),
+        price: new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        release,
+        mint: numberedMint.publicKey,
+        releaseSigner,
+        paymentMint: paymentMint.publicKey,
+        royaltyTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const numberedInit = await sendAndConfirm(numberedInitIx, artist, [numberedMint]);
+    expect(numberedInit.value.err).to.be.null;
+
+    const fungiblePurchaseIx = await program.methods
+      .releasePurchase(
+        new anchor.BN(RELEASE_PRICE),
+        releaseSignerBump,
+      )
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        receiver: purchaser.publicKey,
+        release,
+        releaseSigner,
+        mint: numberedMint.publicKey,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        referrerTokenAccount: null,
+        receiverReleaseTokenAccount: associatedAddress({
+          mint: numberedMint.publicKey,
+          owner: purchaser.publicKey,
+          tokenProgramId: TOKEN_2022_PROGRAM_ID,
+        }),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const fungiblePurchase = await sendAndConfirm(fungiblePurchaseIx, purchaser);
+    expect(fungiblePurchase.value.err).to.not.be.null;
+
+    const purchaseEdition = async () => {
+      const editionMint = Keypair.generate();
+      const receiverEditionTokenAccount = associatedAddress({
+        mint: editionMint.publicKey,
+        owner: purchaser.publicKey,
+        tokenProgramId: TOKEN_2022_PROGRAM_ID,
+      });
+      const purchaseIx = await program.methods
+        .releasePurchaseNumbered(
+          new anchor.BN(RELEASE_PRICE),
+          releaseSignerBump,
+        )
+        .accountsStrict({
+          payer: purchaser.publicKey,
+          receiver: purchaser.publicKey,
+          release,
+          releaseSigner,
+          mint: numberedMint.publicKey,
+          editionMint: editionMint.publicKey,
+          paymentMint: paymentMint.publicKey,
+          paymentTokenAccount: purchaserAta,
+          royaltyTokenAccount,
+          receiverEditionTokenAccount,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+      const result = await sendAndConfirm(purchaseIx, purchaser, [editionMint]);
+      return { result, editionMint, receiverEditionTokenAccount };
+    };
+
+    const first = await purchaseEdition();
+    expect(first.result.value.err).to.be.null;
+    const second = await purchaseEdition();
+    expect(second.result.value.err).to.be.null;
+
+    const editionTokenBalance = await lightConnection.getTokenAccountBalance(second.receiverEditionTokenAccount, 'confirmed');
+    expect(Number(editionTokenBalance.value.amount)).to.equal(1);
+    const metadata = await getTokenMetadata(lightConnection, second.editionMint.publicKey, 'confirmed');
+    expect(metadata.name).to.equal("Nina Numbered #2");
+    expect(metadata.additionalMetadata).to.deep.include(["edition", "2"]);
+    expect(metadata.additionalMetadata).to.deep.include(["release", numberedMint.publicKey.toBase58()]);
+
+    const soldOut = await purchaseEdition();
+    expect(soldOut.result.value.err).to.not.be.null;
+
+    // the edition size is fixed once editions are numbered against it
+    const resizeIx = await program.methods
+      .releaseUpdatePartial({
+        uri: null,
+        name: null,
+        symbol: null,
+        price: null,
+        totalSupply: new anchor.BN(1),
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: null,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        releaseSigner,
+        release,
+        mint: numberedMint.publicKey,
+        label: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const resize = await sendAndConfirm(resizeIx, artist);
+    expect(resize.value.err).to.not.be.null;
+
+    // holding a numbered edition counts as holding the release for tips
+    const requireHolderIx = await program.methods
+      .releaseUpdatePartial({
+        uri: null,
+        name: null,
+        symbol: null,
+        price: null,
+        totalSupply: null,
+        additionalMetadata: null,
+        saleEnd: null,
+        referralFee: null,
+        tipRequiresHolder: true,
+        releaseSignerBump,
+      })
+      .accountsStrict({
+        payer: artist.publicKey,
+        authority: artist.publicKey,
+        releaseSigner,
+        release,
+        mint: numberedMint.publicKey,
+        label: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    const requireHolder = await sendAndConfirm(requireHolderIx, artist);
+    expect(requireHolder.value.err).to.be.null;
+    const tipIx = await program.methods
+      .releaseTip(new anchor.BN(RELEASE_PRICE))
+      .accountsStrict({
+        payer: purchaser.publicKey,
+        tipper: purchaser.publicKey,
+        release,
+        paymentMint: paymentMint.publicKey,
+        paymentTokenAccount: purchaserAta,
+        royaltyTokenAccount,
+        tipperReleaseTokenAccount: first.receiverEditionTokenAccount,
+        releaseTier: null,
+        holderMint: first.editionMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    const tip = await sendAndConfirm(tipIx, purchaser);
+    expect(tip.value.err).to.be.null;
+  });
 });
 
 const buildAndSendReleaseInitV2Transaction = async (
@@ -819,6 +4404,19 @@ const buildAndSendReleaseInitV2Transaction = async (
   mint: Keypair,
   lookupTableAddress: PublicKey,
   price: number = RELEASE_PRICE,
+  config: {
+    transferHook: boolean,
+    resaleRoyalty: anchor.BN,
+    nonTransferable: boolean,
+    permanentDelegate: boolean,
+    mintCloseAuthority: boolean,
+    additionalMetadata: { key: string, value: string }[],
+    openEdition: boolean,
+    saleEnd: anchor.BN | null,
+    referralFee: anchor.BN,
+    tipRequiresHolder: boolean,
+  } = { transferHook: false, resaleRoyalty: new anchor.BN(0), nonTransferable: false, permanentDelegate: false, mintCloseAuthority: false, additionalMetadata: [], openEdition: false, saleEnd: null, referralFee: new anchor.BN(0), tipRequiresHolder: false },
+  profile: PublicKey | null = null,
 ) => {
   const [release] = await anchor.web3.PublicKey.findProgramAddress(
     [
@@ -862,14 +4460,26 @@ const buildAndSendReleaseInitV2Transaction = async (
     );
   }
 
+  let releaseProfile = null;
+  if (profile) {
+    [releaseProfile] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(anchor.utils.bytes.utf8.encode("nina-release-profile")),
+        release.toBuffer(),
+      ],
+      program.programId
+    );
+  }
+
   const ix = await program.methods
-    .releaseInitV2(
+    .releaseInitV2WithConfig(
       `https://arweave.net/rb9wx261pn2nCbiHtoqR2vQtZ3MRQ3qcyZeSSCE0Rm4`,
       "Nina Test",
       "NINA",
       new anchor.BN(100),
       new anchor.BN(price),
-      releaseSignerBump
+      releaseSignerBump,
+      config,
     )
     .accountsStrict({
       payer: payer.publicKey,
@@ -879,6 +4489,8 @@ const buildAndSendReleaseInitV2Transaction = async (
       releaseSigner,
       paymentMint: paymentMint.publicKey,
       royaltyTokenAccount,
+      profile,
+      releaseProfile,
       systemProgram: anchor.web3.SystemProgram.programId,
       associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
       tokenProgram: TOKEN_PROGRAM_ID,
@@ -907,6 +4519,265 @@ const buildAndSendReleaseInitV2Transaction = async (
   return { release, txid };
 };
 
+const setupCrowdfundRelease = async (
+  payer: Keypair,
+  artist: Keypair,
+  paymentMint: Keypair,
+  goalCopies: number,
+  secondsToDeadline: number,
+) => {
+  const crowdfundMint = Keypair.generate();
+  const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+    program,
+    payer,
+    artist,
+    lightConnection,
+    paymentMint,
+    crowdfundMint,
+    undefined,
+  );
+  const initBlockHash = await lightConnection.getLatestBlockhash();
+  await lightConnection.confirmTransaction(
+    {
+      blockhash: initBlockHash.blockhash,
+      lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+      signature: txid,
+    },
+    'finalized',
+  );
+
+  const [crowdfund] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(anchor.utils.bytes.utf8.encode("nina-crowdfund")),
+      release.toBuffer(),
+    ],
+    program.programId
+  );
+  const escrowTokenAccount = associatedAddress({
+    mint: paymentMint.publicKey,
+    owner: crowdfund,
+  });
+  const slot = await lightConnection.getSlot('finalized');
+  const blockTime = await lightConnection.getBlockTime(slot);
+
+  const crowdfundInitIx = await program.methods
+    .crowdfundInit(
+      new anchor.BN(0),
+      new anchor.BN(goalCopies),
+      new anchor.BN(blockTime + secondsToDeadline),
+    )
+    .accountsStrict({
+      payer: artist.publicKey,
+      authority: artist.publicKey,
+      release,
+      mint: crowdfundMint.publicKey,
+      crowdfund,
+      paymentMint: paymentMint.publicKey,
+      escrowTokenAccount,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      label: null,
+    })
+    .instruction();
+  const crowdfundInitTxid = await buildSignAndSendTransaction(
+    [modifyComputeUnits, addPriorityFee, crowdfundInitIx],
+    artist,
+    lightConnection,
+    [],
+  );
+  const crowdfundInitBlockHash = await lightConnection.getLatestBlockhash();
+  await lightConnection.confirmTransaction(
+    {
+      blockhash: crowdfundInitBlockHash.blockhash,
+      lastValidBlockHeight: crowdfundInitBlockHash.lastValidBlockHeight,
+      signature: crowdfundInitTxid,
+    },
+    'finalized',
+  );
+
+  return { release, crowdfund, crowdfundMint, escrowTokenAccount };
+};
+
+const sendCrowdfundPurchase = async (
+  buyer: Keypair,
+  buyerAta: PublicKey,
+  release: PublicKey,
+  crowdfund: PublicKey,
+  crowdfundMint: PublicKey,
+  paymentMint: PublicKey,
+  escrowTokenAccount: PublicKey,
+) => {
+  const [releaseSigner, releaseSignerBump] =
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [release.toBuffer()],
+      program.programId
+    );
+  const [contribution] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(anchor.utils.bytes.utf8.encode("nina-contribution")),
+      crowdfund.toBuffer(),
+      buyer.publicKey.toBuffer(),
+    ],
+    program.programId
+  );
+  const ix = await program.methods
+    .crowdfundPurchase(new anchor.BN(RELEASE_PRICE), releaseSignerBump)
+    .accountsStrict({
+      payer: buyer.publicKey,
+      receiver: buyer.publicKey,
+      release,
+      releaseSigner,
+      mint: crowdfundMint,
+      crowdfund,
+      contribution,
+      paymentMint,
+      paymentTokenAccount: buyerAta,
+      escrowTokenAccount,
+      receiverReleaseTokenAccount: associatedAddress({
+        mint: crowdfundMint,
+        owner: buyer.publicKey,
+        tokenProgramId: TOKEN_2022_PROGRAM_ID,
+      }),
+      systemProgram: anchor.web3.SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+    })
+    .instruction();
+  const txid = await buildSignAndSendTransaction(
+    [modifyComputeUnits, addPriorityFee, ix],
+    buyer,
+    lightConnection,
+    [],
+  );
+  const latestBlockHash = await lightConnection.getLatestBlockhash();
+  return lightConnection.confirmTransaction(
+    {
+      blockhash: latestBlockHash.blockhash,
+      lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+      signature: txid,
+    },
+    'finalized',
+  );
+};
+
+const setupPreorderRelease = async (
+  payer: Keypair,
+  artist: Keypair,
+  paymentMint: Keypair,
+  secondsToReleaseDate: number,
+  config?: Parameters<typeof buildAndSendReleaseInitV2Transaction>[8],
+) => {
+  const preorderMint = Keypair.generate();
+  const { release, txid } = await buildAndSendReleaseInitV2Transaction(
+    program,
+    payer,
+    artist,
+    lightConnection,
+    paymentMint,
+    preorderMint,
+    undefined,
+    RELEASE_PRICE,
+    config,
+  );
+  const initBlockHash = await lightConnection.getLatestBlockhash();
+  await lightConnection.confirmTransaction(
+    {
+      blockhash: initBlockHash.blockhash,
+      lastValidBlockHeight: initBlockHash.lastValidBlockHeight,
+      signature: txid,
+    },
+    'finalized',
+  );
+
+  const [releaseSigner, releaseSignerBump] = anchor.web3.PublicKey.findProgramAddressSync(
+    [release.toBuffer()],
+    program.programId
+  );
+  const escrowTokenAccount = associatedAddress({
+    mint: paymentMint.publicKey,
+    owner: releaseSigner,
+  });
+  const slot = await lightConnection.getSlot('finalized');
+  const blockTime = await lightConnection.getBlockTime(slot);
+
+  const preorderInitIx = await program.methods
+    .releasePreorderInit(new anchor.BN(blockTime + secondsToReleaseDate))
+    .accountsStrict({
+      payer: artist.publicKey,
+      authority: artist.publicKey,
+      release,
+      releaseSigner,
+      paymentMint: paymentMint.publicKey,
+      escrowTokenAccount,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      label: null,
+    })
+    .instruction();
+  const preorderInitTxid = await buildSignAndSendTransaction(
+    [modifyComputeUnits, addPriorityFee, preorderInitIx],
+    artist,
+    lightConnection,
+    [],
+  );
+  const preorderInitBlockHash = await lightConnection.getLatestBlockhash();
+  await lightConnection.confirmTransaction(
+    {
+      blockhash: preorderInitBlockHash.blockhash,
+      lastValidBlockHeight: preorderInitBlockHash.lastValidBlockHeight,
+      signature: preorderInitTxid,
+    },
+    'finalized',
+  );
+
+  const [preorderReceipt] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(anchor.utils.bytes.utf8.encode("nina-preorder")),
+      release.toBuffer(),
+      purchaser.publicKey.toBuffer(),
+    ],
+    program.programId
+  );
+  const preorderIx = await program.methods
+    .releasePreorder(new anchor.BN(RELEASE_PRICE))
+    .accountsStrict({
+      payer: purchaser.publicKey,
+      receiver: purchaser.publicKey,
+      release,
+      releaseSigner,
+      mint: preorderMint.publicKey,
+      preorderReceipt,
+      paymentMint: paymentMint.publicKey,
+      paymentTokenAccount: purchaserAta,
+      escrowTokenAccount,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  const preorderTxid = await buildSignAndSendTransaction(
+    [modifyComputeUnits, addPriorityFee, preorderIx],
+    purchaser,
+    lightConnection,
+    [],
+  );
+  const preorderBlockHash = await lightConnection.getLatestBlockhash();
+  const preorder = await lightConnection.confirmTransaction(
+    {
+      blockhash: preorderBlockHash.blockhash,
+      lastValidBlockHeight: preorderBlockHash.lastValidBlockHeight,
+      signature: preorderTxid,
+    },
+    'finalized',
+  );
+  expect(preorder.value.err).to.be.null;
+
+  return { release, releaseSigner, releaseSignerBump, preorderMint, escrowTokenAccount, preorderReceipt };
+};
+
 export function associatedAddress({
   mint,
   owner,